@@ -1,3 +1,5 @@
+advent_of_code::solution!(1);
+
 fn parse_input(input: &str) -> Vec<usize> {
     input.lines().map(|x| x.parse::<usize>().unwrap()).collect()
 }
@@ -11,15 +13,15 @@ fn get_num_contiguous_increases(usize_list: Vec<usize>) -> usize {
     })
 }
 
-pub fn part_one(input: &str) -> usize {
-    get_num_contiguous_increases(parse_input(input))
+pub fn part_one(input: &str) -> Option<usize> {
+    Some(get_num_contiguous_increases(parse_input(input)))
 }
 
-pub fn part_two(input: &str) -> usize {
+pub fn part_two(input: &str) -> Option<usize> {
     let triplet_window_sums: Vec<usize> = parse_input(input)
         .windows(3)
         .map(|triplet| triplet.iter().sum::<usize>())
         .collect();
 
-    get_num_contiguous_increases(triplet_window_sums)
+    Some(get_num_contiguous_increases(triplet_window_sums))
 }
@@ -6,6 +6,8 @@ use nom::{
     IResult,
 };
 
+advent_of_code::solution!(2);
+
 #[derive(Debug)]
 enum Command {
     Forward(u64),
@@ -32,7 +34,7 @@ fn parse_input(input: &str) -> Vec<Command> {
     input.lines().map(parse_command).collect()
 }
 
-pub fn part_one(input: &str) -> i64 {
+pub fn part_one(input: &str) -> Option<i64> {
     let commands = parse_input(input);
 
     let mut horizontal_position: i64 = 0;
@@ -43,10 +45,10 @@ pub fn part_one(input: &str) -> i64 {
         Command::Down(v) => depth += *v as i64,
         Command::Up(v) => depth -= *v as i64,
     });
-    horizontal_position * depth
+    Some(horizontal_position * depth)
 }
 
-pub fn part_two(input: &str) -> i64 {
+pub fn part_two(input: &str) -> Option<i64> {
     let commands = parse_input(input);
     let mut horizontal_position: i64 = 0;
     let mut depth: i64 = 0;
@@ -59,5 +61,5 @@ pub fn part_two(input: &str) -> i64 {
         Command::Down(v) => aim += *v as i64,
         Command::Up(v) => aim -= *v as i64,
     });
-    horizontal_position * depth
+    Some(horizontal_position * depth)
 }
@@ -1,5 +1,7 @@
 use std::cmp::Ordering;
 
+advent_of_code::solution!(3);
+
 fn parse_input(input: &str) -> Vec<Vec<u32>> {
     input
         .lines()
@@ -7,7 +9,7 @@ fn parse_input(input: &str) -> Vec<Vec<u32>> {
         .collect()
 }
 
-pub fn part_one(input: &str) -> u32 {
+pub fn part_one(input: &str) -> Option<u32> {
     let list_of_lists = parse_input(input);
     let length = list_of_lists.len();
 
@@ -37,18 +39,19 @@ pub fn part_one(input: &str) -> u32 {
             }
         });
 
-    gamma_rate * epsilon_rate
+    Some(gamma_rate * epsilon_rate)
 }
 
-pub fn part_two(input: &str) -> u32 {
+pub fn part_two(input: &str) -> Option<u32> {
     let list_of_lists = parse_input(input);
 
     let oxygen_generator_rating = get_rating(&list_of_lists, true);
     let co2_scrubber_rating = get_rating(&list_of_lists, false);
 
-    oxygen_generator_rating * co2_scrubber_rating
+    Some(oxygen_generator_rating * co2_scrubber_rating)
 }
 
+#[allow(clippy::ptr_arg)]
 fn get_rating(list_of_lists: &Vec<Vec<u32>>, use_most_common: bool) -> u32 {
     let inner_list_length = list_of_lists.first().unwrap().len();
 
@@ -1,3 +1,5 @@
+advent_of_code::solution!(4);
+
 type Tile = (u32, bool);
 #[derive(Debug)]
 struct Board(Vec<Vec<Tile>>);
@@ -50,6 +52,7 @@ impl Board {
     }
 }
 
+#[allow(clippy::redundant_closure)]
 fn parse_input(input: &str) -> (Vec<u32>, Vec<Board>) {
     let numbers = input
         .lines()
@@ -69,7 +72,7 @@ fn parse_input(input: &str) -> (Vec<u32>, Vec<Board>) {
     (numbers, boards)
 }
 
-pub fn part_one(input: &str) -> u32 {
+pub fn part_one(input: &str) -> Option<u32> {
     let (numbers, mut boards) = parse_input(input);
 
     let mut is_game_over = false;
@@ -89,10 +92,10 @@ pub fn part_one(input: &str) -> u32 {
         }
     }
 
-    final_score
+    Some(final_score)
 }
 
-pub fn part_two(input: &str) -> u32 {
+pub fn part_two(input: &str) -> Option<u32> {
     let (numbers, mut boards) = parse_input(input);
 
     // let mut boards = HashSet::from_iter(boards);
@@ -112,5 +115,5 @@ pub fn part_two(input: &str) -> u32 {
         });
     }
 
-    final_score
+    Some(final_score)
 }
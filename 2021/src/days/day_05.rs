@@ -1,5 +1,7 @@
 use std::collections::HashMap;
 
+advent_of_code::solution!(5);
+
 // #[derive(Debug)]
 type LineSegment = ((u32, u32), (u32, u32));
 
@@ -25,7 +27,7 @@ fn parse_input(input: &str) -> Vec<LineSegment> {
         .collect()
 }
 
-pub fn part_one(input: &str) -> u32 {
+pub fn part_one(input: &str) -> Option<u32> {
     let line_segments = parse_input(input);
     //println!("{:?}", line_segments);
     let mut intersection_count = 0;
@@ -71,10 +73,10 @@ pub fn part_one(input: &str) -> u32 {
             }
         });
 
-    intersection_count
+    Some(intersection_count)
 }
 
-pub fn part_two(input: &str) -> u32 {
+pub fn part_two(input: &str) -> Option<u32> {
     let line_segments = parse_input(input);
     //println!("{:?}", line_segments);
     let mut intersection_count = 0;
@@ -142,5 +144,5 @@ pub fn part_two(input: &str) -> u32 {
             }
         });
 
-    intersection_count
+    Some(intersection_count)
 }
@@ -3,23 +3,11 @@ pub mod day_02;
 pub mod day_03;
 pub mod day_04;
 pub mod day_05;
-// pub mod day_06;
-// pub mod day_07;
-// pub mod day_08;
-// pub mod day_09;
-// pub mod day_10;
-// pub mod day_11;
-// pub mod day_12;
-// pub mod day_13;
-// pub mod day_14;
-// pub mod day_15;
-// pub mod day_16;
-// pub mod day_17;
-// pub mod day_18;
-// pub mod day_19;
-// pub mod day_20;
-// pub mod day_21;
-// pub mod day_22;
-// pub mod day_23;
-// pub mod day_24;
-// pub mod day_25;
+
+advent_of_code::days! {
+    day_01,
+    day_02,
+    day_03,
+    day_04,
+    day_05,
+}
@@ -1,3 +1,5 @@
+advent_of_code::solution!(2);
+
 fn parse_input(input: &str) -> Vec<(char, char)> {
     input
         .lines()
@@ -10,9 +12,9 @@ fn parse_input(input: &str) -> Vec<(char, char)> {
 
 // rps 123
 // win 6, draw 3, loss 0
-pub fn part_one(input: &str) -> usize {
+pub fn part_one(input: &str) -> Option<usize> {
     let rounds = parse_input(input);
-    rounds.iter().fold(0, |acc, round| {
+    Some(rounds.iter().fold(0, |acc, round| {
         let pity_points = match round.1 {
             'X' => 1,
             'Y' => 2,
@@ -26,13 +28,13 @@ pub fn part_one(input: &str) -> usize {
             _ => panic!("jinkies"),
         };
         acc + pity_points + victory_points
-    })
+    }))
 }
 
 // X lose, Y draw, Z win
-pub fn part_two(input: &str) -> usize {
+pub fn part_two(input: &str) -> Option<usize> {
     let rounds = parse_input(input);
-    rounds.iter().fold(0, |acc, round| {
+    Some(rounds.iter().fold(0, |acc, round| {
         let victory_points = match round.1 {
             'X' => 0,
             'Y' => 3,
@@ -46,5 +48,5 @@ pub fn part_two(input: &str) -> usize {
             _ => panic!("jinkies"),
         };
         acc + victory_points + pity_points
-    })
+    }))
 }
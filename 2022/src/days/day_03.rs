@@ -1,4 +1,6 @@
-pub fn part_one(input: &str) -> usize {
+advent_of_code::solution!(3);
+
+pub fn part_one(input: &str) -> Option<usize> {
     let chars_in_both_compartments_per_rucksack = input
         .lines()
         .map(|line| {
@@ -10,10 +12,10 @@ pub fn part_one(input: &str) -> usize {
                 .unwrap()
         })
         .collect();
-    get_sum(chars_in_both_compartments_per_rucksack)
+    Some(get_sum(chars_in_both_compartments_per_rucksack))
 }
 
-pub fn part_two(input: &str) -> usize {
+pub fn part_two(input: &str) -> Option<usize> {
     let rucksacks: Vec<&str> = input.lines().collect();
     let chars_common_in_each_three_rucksacks = rucksacks
         .chunks(3)
@@ -24,9 +26,10 @@ pub fn part_two(input: &str) -> usize {
                 .unwrap()
         })
         .collect();
-    get_sum(chars_common_in_each_three_rucksacks)
+    Some(get_sum(chars_common_in_each_three_rucksacks))
 }
 
+#[allow(clippy::manual_range_contains)]
 fn get_sum(chars: Vec<char>) -> usize {
     chars
         .iter()
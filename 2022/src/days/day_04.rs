@@ -1,3 +1,5 @@
+advent_of_code::solution!(4);
+
 fn parse_input(input: &str) -> Vec<((usize, usize), (usize, usize))> {
     input
         .lines()
@@ -20,18 +22,22 @@ fn parse_input(input: &str) -> Vec<((usize, usize), (usize, usize))> {
         .collect()
 }
 
-pub fn part_one(input: &str) -> usize {
+pub fn part_one(input: &str) -> Option<usize> {
     let assignments = parse_input(input);
-    assignments
-        .iter()
-        .filter(|(a, b)| a.0 <= b.0 && a.1 >= b.1 || b.0 <= a.0 && b.1 >= a.1)
-        .count()
+    Some(
+        assignments
+            .iter()
+            .filter(|(a, b)| a.0 <= b.0 && a.1 >= b.1 || b.0 <= a.0 && b.1 >= a.1)
+            .count(),
+    )
 }
 
-pub fn part_two(input: &str) -> usize {
+pub fn part_two(input: &str) -> Option<usize> {
     let assignments = parse_input(input);
-    assignments
-        .iter()
-        .filter(|(a, b)| !(a.1 < b.0 || a.0 > b.1))
-        .count()
+    Some(
+        assignments
+            .iter()
+            .filter(|(a, b)| !(a.1 < b.0 || a.0 > b.1))
+            .count(),
+    )
 }
@@ -1,3 +1,10 @@
+advent_of_code::solution!(5);
+
+#[allow(
+    clippy::is_digit_ascii_radix,
+    clippy::type_complexity,
+    clippy::while_let_on_iterator
+)]
 fn parse_input(input: &str) -> (Vec<Vec<char>>, Vec<(usize, usize, usize)>) {
     let num_stacks = (input.lines().next().unwrap().len() + 1) / 4;
 
@@ -40,7 +47,7 @@ fn parse_input(input: &str) -> (Vec<Vec<char>>, Vec<(usize, usize, usize)>) {
     (supply_crate_stacks, crane_instructions)
 }
 
-pub fn part_one(input: &str) -> String {
+pub fn part_one(input: &str) -> Option<String> {
     let (mut supply_crate_stacks, crane_instructions) = parse_input(input);
 
     for (quantity, from, to) in crane_instructions {
@@ -50,13 +57,15 @@ pub fn part_one(input: &str) -> String {
         });
     }
 
-    supply_crate_stacks
-        .into_iter()
-        .map(|mut stack| stack.pop().unwrap())
-        .collect()
+    Some(
+        supply_crate_stacks
+            .into_iter()
+            .map(|mut stack| stack.pop().unwrap())
+            .collect(),
+    )
 }
 
-pub fn part_two(input: &str) -> String {
+pub fn part_two(input: &str) -> Option<String> {
     let (mut supply_crate_stacks, crane_instructions) = parse_input(input);
 
     for (quantity, from, to) in crane_instructions {
@@ -65,8 +74,10 @@ pub fn part_two(input: &str) -> String {
         supply_crate_stacks[to - 1].append(&mut supply_crates);
     }
 
-    supply_crate_stacks
-        .into_iter()
-        .map(|mut stack| stack.pop().unwrap())
-        .collect()
+    Some(
+        supply_crate_stacks
+            .into_iter()
+            .map(|mut stack| stack.pop().unwrap())
+            .collect(),
+    )
 }
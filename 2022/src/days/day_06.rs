@@ -1,9 +1,11 @@
-pub fn part_one(input: &str) -> usize {
-    get_index_after_first_unique_window(input, 4)
+advent_of_code::solution!(6);
+
+pub fn part_one(input: &str) -> Option<usize> {
+    Some(get_index_after_first_unique_window(input, 4))
 }
 
-pub fn part_two(input: &str) -> usize {
-    get_index_after_first_unique_window(input, 14)
+pub fn part_two(input: &str) -> Option<usize> {
+    Some(get_index_after_first_unique_window(input, 14))
 }
 
 fn get_index_after_first_unique_window(input: &str, window_size: usize) -> usize {
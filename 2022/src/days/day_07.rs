@@ -1,5 +1,7 @@
 use std::vec;
 
+advent_of_code::solution!(7);
+
 #[derive(Debug)]
 struct Directory {
     size: usize,
@@ -26,6 +28,7 @@ fn parse_input(input: &str) -> Directory {
     root_directory
 }
 
+#[allow(clippy::while_let_on_iterator)]
 fn recursively_parse(lines: &mut impl Iterator<Item = String>) -> Directory {
     // the first line is going to be ls
     // we can hydrate the directory size
@@ -84,6 +87,7 @@ fn recursively_parse(lines: &mut impl Iterator<Item = String>) -> Directory {
     Directory { size, children }
 }
 
+#[allow(clippy::redundant_closure)]
 fn recursively_update_and_get_size(directory: &mut Directory) -> usize {
     directory.size += directory
         .children
@@ -106,13 +110,13 @@ fn recursively_get_sizes_within_threshold(directory: &Directory, threshold: usiz
     v
 }
 
-pub fn part_one(input: &str) -> usize {
+pub fn part_one(input: &str) -> Option<usize> {
     let root_directory = parse_input(input);
 
     let sizes_below_threshold = recursively_get_sizes_within_threshold(&root_directory, 100000);
     // println!("{:?}", sizes_below_threshold);
 
-    sizes_below_threshold.iter().sum()
+    Some(sizes_below_threshold.iter().sum())
 }
 
 fn recursively_get_sizes(directory: &Directory) -> Vec<usize> {
@@ -123,13 +127,13 @@ fn recursively_get_sizes(directory: &Directory) -> Vec<usize> {
         .for_each(|c| v.append(&mut recursively_get_sizes(c)));
     v
 }
-pub fn part_two(input: &str) -> usize {
+pub fn part_two(input: &str) -> Option<usize> {
     let root_directory = parse_input(input);
     let total_space = 70000000;
     let unused_space = total_space - root_directory.size;
     let unused_space_needed = 30000000;
     if unused_space >= unused_space_needed {
-        return 0;
+        return Some(0);
     }
     let target_size_to_delete = unused_space_needed - unused_space;
 
@@ -146,5 +150,5 @@ pub fn part_two(input: &str) -> usize {
         .unwrap();
     // println!("{}", size_to_delete);
 
-    *size_to_delete
+    Some(*size_to_delete)
 }
@@ -1,5 +1,7 @@
 use std::collections::HashSet;
 
+advent_of_code::solution!(9);
+
 fn parse_input(input: &str) -> Vec<(char, u32)> {
     input
         .lines()
@@ -11,7 +13,7 @@ fn parse_input(input: &str) -> Vec<(char, u32)> {
         .collect()
 }
 
-pub fn part_one(input: &str) -> usize {
+pub fn part_one(input: &str) -> Option<usize> {
     let motions = parse_input(input);
     // println!("motions: {:?}", motions);
     let mut head = (0, 0);
@@ -48,10 +50,10 @@ pub fn part_one(input: &str) -> usize {
     // v.sort();
     // println!("{:?}", v);
 
-    visited_positions.len()
+    Some(visited_positions.len())
 }
 
-pub fn part_two(input: &str) -> usize {
+pub fn part_two(input: &str) -> Option<usize> {
     let motions = parse_input(input);
 
     let mut knots = [(0, 0); 10];
@@ -81,5 +83,5 @@ pub fn part_two(input: &str) -> usize {
         }
     }
 
-    visited_positions.len()
+    Some(visited_positions.len())
 }
@@ -1,3 +1,5 @@
+advent_of_code::solution!(10);
+
 #[derive(Debug)]
 enum Instruction {
     Noop,
@@ -23,7 +25,7 @@ fn try_update_signal_strengths(cycle: i32, x: i32, signal_strengths: &mut Vec<i3
         signal_strengths.push(cycle * x);
     }
 }
-pub fn part_one(input: &str) -> i32 {
+pub fn part_one(input: &str) -> Option<i32> {
     let instructions = parse_input(input);
     let mut cycle = 1;
     let mut x = 1;
@@ -41,7 +43,7 @@ pub fn part_one(input: &str) -> i32 {
         }
     }
 
-    signal_strengths.into_iter().sum()
+    Some(signal_strengths.into_iter().sum())
 }
 
 fn draw(cycle: i32, x: i32, crt_screen: &mut String) {
@@ -56,7 +58,7 @@ fn draw(cycle: i32, x: i32, crt_screen: &mut String) {
         crt_screen.push('.');
     }
 }
-pub fn part_two(input: &str) -> String {
+pub fn part_two(input: &str) -> Option<String> {
     let instructions = parse_input(input);
     // 0-counting the cycle makes this a lot easier
     let mut cycle = 0;
@@ -74,5 +76,5 @@ pub fn part_two(input: &str) -> String {
             }
         }
     }
-    crt_screen
+    Some(crt_screen)
 }
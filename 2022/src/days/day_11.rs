@@ -1,3 +1,5 @@
+advent_of_code::solution!(11);
+
 struct Monkey {
     items: Vec<usize>,
     operation: Box<dyn Fn(usize) -> usize>,
@@ -5,6 +7,7 @@ struct Monkey {
     test_divisor: usize, // I hope this helps part 2
 }
 
+#[allow(clippy::manual_is_multiple_of)]
 fn parse_input(input: &str) -> Vec<Monkey> {
     input
         .split("\n\n")
@@ -68,7 +71,7 @@ fn parse_input(input: &str) -> Vec<Monkey> {
         .collect()
 }
 
-pub fn part_one(input: &str) -> usize {
+pub fn part_one(input: &str) -> Option<usize> {
     let mut monkeys = parse_input(input);
 
     // monkey inspects its first item
@@ -101,11 +104,12 @@ pub fn part_one(input: &str) -> usize {
     // println!("{:?}", mi);
 
     monkey_actions.sort();
-    monkey_actions.iter().rev().take(2).product()
+    Some(monkey_actions.iter().rev().take(2).product())
     // .fold(1, |acc, e| acc * e)
 }
 
-pub fn part_two(input: &str) -> usize {
+#[allow(clippy::assign_op_pattern)]
+pub fn part_two(input: &str) -> Option<usize> {
     let mut monkeys = parse_input(input);
 
     // monkey inspects its first item
@@ -151,6 +155,6 @@ pub fn part_two(input: &str) -> usize {
 
     // println!("final monkey_actions: {:?}", monkey_actions);
 
-    monkey_actions.iter().rev().take(2).product()
+    Some(monkey_actions.iter().rev().take(2).product())
     // .fold(1, |acc, e| acc * e)
 }
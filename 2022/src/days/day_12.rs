@@ -1,5 +1,7 @@
 use std::fmt::Debug;
 
+advent_of_code::solution!(12);
+
 type Position = (usize, usize);
 type Grid = Vec<Vec<u32>>;
 type ShortestPaths = Vec<Vec<Option<usize>>>;
@@ -30,12 +32,13 @@ fn parse_input(input: &str) -> (Position, Position, Grid) {
     (start, end, grid)
 }
 
-pub fn part_one(input: &str) -> usize {
+pub fn part_one(input: &str) -> Option<usize> {
     let (start, end, grid) = parse_input(input);
 
-    get_shortest_path(start, end, &grid).unwrap()
+    Some(get_shortest_path(start, end, &grid).unwrap())
 }
 
+#[allow(clippy::needless_borrow)]
 fn get_shortest_path(start: Position, end: Position, grid: &Grid) -> Option<usize> {
     // I need to memoize the shortest path from the starting position to each other position. I'm just storing the number of steps, though, not the actual path
     // to do this, I need to recurse
@@ -59,10 +62,12 @@ fn get_shortest_path(start: Position, end: Position, grid: &Grid) -> Option<usiz
     shortest_paths[end.0][end.1]
 }
 
+#[allow(clippy::ptr_arg)]
 fn _print_grid<T: Debug>(shortest_paths: &Vec<Vec<T>>) {
     shortest_paths.iter().for_each(|row| println!("{:?}", row));
 }
 
+#[allow(clippy::unnecessary_map_or)]
 fn recursively_solve(
     (current_row, current_col): Position,
     grid: &Grid,
@@ -124,38 +129,43 @@ fn recursively_solve(
     }
 }
 
-pub fn part_two(input: &str) -> usize {
+#[allow(clippy::map_flatten)]
+pub fn part_two(input: &str) -> Option<usize> {
     // this is going to be basically the same as part_one except I want to iterate over all possible starting positions and find the shortest path among them. I'm not going to bother with optimizations, but one improvement would be to abort the recursive function the moment we see a favorable step to another 'a'.
     // A better strategy would probably be to compose a list of all possible starting positions and then tweak the recursive function to also return a list of all 'a' positions that were visited with the one closest to the end standing out (like the first element in the list if you sort it): if you do that, you can immediately discount all of the other starting positions that showed up in that list and reassess the shortest path to the end from that 'a' that's closest. Then you can keep iterating over the list of unseen potential start positions
 
     let (_start, end, grid) = parse_input(input);
 
-    grid.iter()
-        .enumerate()
-        .map(|(row_index, row)| {
-            // this is a problem for another day, but there are ownership problems with this higher-order-function implementation
-            // row.iter()
-            //     .enumerate()
-            //     .filter(|(_, elevation)| **elevation == 'a' as u32)
-            //     .map(|(col_index, _)| get_shortest_path((row_index, col_index), end, &grid))
-
-            let mut v = vec![];
-            for (col_index, _) in row
-                .iter()
-                .enumerate()
-                // .inspect(|(col_index, elevation)| {
-                //     println!("({}, {}): {}", row_index, col_index, elevation)
-                // })
-                .filter(|(_, elevation)| **elevation == 'a' as u32)
-            {
-                if let Some(shortest_path) = get_shortest_path((row_index, col_index), end, &grid) {
-                    // println!("considering start ({}, {})", row_index, col_index);
-                    v.push(shortest_path);
+    Some(
+        grid.iter()
+            .enumerate()
+            .map(|(row_index, row)| {
+                // this is a problem for another day, but there are ownership problems with this higher-order-function implementation
+                // row.iter()
+                //     .enumerate()
+                //     .filter(|(_, elevation)| **elevation == 'a' as u32)
+                //     .map(|(col_index, _)| get_shortest_path((row_index, col_index), end, &grid))
+
+                let mut v = vec![];
+                for (col_index, _) in row
+                    .iter()
+                    .enumerate()
+                    // .inspect(|(col_index, elevation)| {
+                    //     println!("({}, {}): {}", row_index, col_index, elevation)
+                    // })
+                    .filter(|(_, elevation)| **elevation == 'a' as u32)
+                {
+                    if let Some(shortest_path) =
+                        get_shortest_path((row_index, col_index), end, &grid)
+                    {
+                        // println!("considering start ({}, {})", row_index, col_index);
+                        v.push(shortest_path);
+                    }
                 }
-            }
-            v
-        })
-        .flatten()
-        .min()
-        .unwrap()
+                v
+            })
+            .flatten()
+            .min()
+            .unwrap(),
+    )
 }
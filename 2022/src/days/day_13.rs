@@ -1,5 +1,7 @@
 use std::{cmp::Ordering, fmt::Debug};
 
+advent_of_code::solution!(13);
+
 type Packet = Vec<PacketElement>;
 
 #[derive(Debug, Clone)]
@@ -24,6 +26,7 @@ impl PartialEq for PacketElement {
     }
 }
 impl Eq for PacketElement {}
+#[allow(clippy::non_canonical_partial_ord_impl)]
 impl PartialOrd for PacketElement {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         match (&self, other) {
@@ -79,6 +82,7 @@ fn parse_line(line: &str) -> Packet {
     recursively_parse_packet_line_with_iterator(&mut chars)
 }
 
+#[allow(clippy::is_digit_ascii_radix)]
 fn recursively_parse_packet_line_with_iterator(chars: &mut dyn Iterator<Item = char>) -> Packet {
     let mut packet = Packet::new();
     // assume a [ was just consumed from the iterator before this function was called
@@ -120,20 +124,23 @@ fn recursively_parse_packet_line_with_iterator(chars: &mut dyn Iterator<Item = c
     panic!("an input line didn't have a ] to match a [");
 }
 
-pub fn part_one(input: &str) -> usize {
+pub fn part_one(input: &str) -> Option<usize> {
     let packet_pairs = parse_input_as_packet_pairs(input);
 
     // packet_pairs
     //     .iter()
     //     .for_each(|(first, second)| println!("{:?}\n{:?}\n", first, second));
 
-    packet_pairs
-        .iter()
-        .enumerate()
-        .map(|(index, (first, second))| if first < second { index + 1 } else { 0 })
-        .sum()
+    Some(
+        packet_pairs
+            .iter()
+            .enumerate()
+            .map(|(index, (first, second))| if first < second { index + 1 } else { 0 })
+            .sum(),
+    )
 }
 
+#[allow(clippy::redundant_closure)]
 fn parse_input_as_unpaired_packets(input: &str) -> Vec<Packet> {
     input
         .replace("\n\n", "\n")
@@ -142,7 +149,7 @@ fn parse_input_as_unpaired_packets(input: &str) -> Vec<Packet> {
         .collect()
 }
 
-pub fn part_two(input: &str) -> usize {
+pub fn part_two(input: &str) -> Option<usize> {
     let mut packets = parse_input_as_unpaired_packets(input);
 
     let divider_packet_1 = vec![PacketElement::List(vec![PacketElement::Number(2)])];
@@ -154,10 +161,12 @@ pub fn part_two(input: &str) -> usize {
 
     // packets.iter().for_each(|packet| println!("{:?}", packet));
 
-    packets
-        .iter()
-        .enumerate()
-        .filter(|(_, packet)| **packet == divider_packet_1 || **packet == divider_packet_2)
-        .map(|(index, _)| index + 1)
-        .product()
+    Some(
+        packets
+            .iter()
+            .enumerate()
+            .filter(|(_, packet)| **packet == divider_packet_1 || **packet == divider_packet_2)
+            .map(|(index, _)| index + 1)
+            .product(),
+    )
 }
@@ -1,3 +1,5 @@
+advent_of_code::solution!(14);
+
 #[derive(Clone, Debug, PartialEq)]
 enum Tile {
     Air,
@@ -92,6 +94,7 @@ fn parse_input(input: &str) -> (Vec<Vec<Tile>>, usize) {
     (grid, normalized_x_from_which_sand_falls)
 }
 
+#[allow(clippy::ptr_arg)]
 fn pour_sand(grid: &mut Vec<Vec<Tile>>, x_from_which_sand_falls: usize) {
     'pour_sand_grains: loop {
         // grid.iter().for_each(|row| {
@@ -141,7 +144,7 @@ fn pour_sand(grid: &mut Vec<Vec<Tile>>, x_from_which_sand_falls: usize) {
     }
 }
 
-pub fn part_one(input: &str) -> usize {
+pub fn part_one(input: &str) -> Option<usize> {
     let (mut grid, x_from_which_sand_falls) = parse_input(input);
 
     // println!(
@@ -151,12 +154,14 @@ pub fn part_one(input: &str) -> usize {
 
     pour_sand(&mut grid, x_from_which_sand_falls);
 
-    grid.iter()
-        .map(|row| row.iter().filter(|tile| **tile == Tile::Sand).count())
-        .sum()
+    Some(
+        grid.iter()
+            .map(|row| row.iter().filter(|tile| **tile == Tile::Sand).count())
+            .sum(),
+    )
 }
 
-pub fn part_two(input: &str) -> usize {
+pub fn part_two(input: &str) -> Option<usize> {
     let (mut grid, mut x_from_which_sand_falls) = parse_input(input);
 
     // the grid has to be expanded to a width of 1+2*(num_rows+2), and the x from which sand falls has to be reset to the middle of it
@@ -193,7 +198,9 @@ pub fn part_two(input: &str) -> usize {
 
     pour_sand(&mut grid, x_from_which_sand_falls);
 
-    grid.iter()
-        .map(|row| row.iter().filter(|tile| **tile == Tile::Sand).count())
-        .sum()
+    Some(
+        grid.iter()
+            .map(|row| row.iter().filter(|tile| **tile == Tile::Sand).count())
+            .sum(),
+    )
 }
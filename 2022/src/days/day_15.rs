@@ -1,5 +1,7 @@
 use regex::Regex;
 
+advent_of_code::solution!(15);
+
 #[derive(Debug)]
 struct Sensor {
     x: i64,
@@ -55,7 +57,7 @@ fn parse_input(input: &str) -> (Vec<Sensor>, Vec<Beacon>) {
     sensors_and_beacons
 }
 
-pub fn part_one(input: &str) -> u64 {
+pub fn part_one(input: &str) -> Option<u64> {
     let (sensors, beacons) = parse_input(input);
     // println!("sensors: {:?}", sensors);
     // println!("beacons: {:?}", beacons);
@@ -133,7 +135,7 @@ pub fn part_one(input: &str) -> u64 {
     //     number_of_beacons_at_y_of_interest
     // );
 
-    number_of_x_indexes_visible_to_sensors - number_of_beacons_at_y_of_interest
+    Some(number_of_x_indexes_visible_to_sensors - number_of_beacons_at_y_of_interest)
 }
 
 // const MAX_X_AND_Y: i64 = 20;
@@ -157,7 +159,8 @@ fn get_tuning_value((x, y): (i64, i64)) -> i64 {
     x * 4000000 + y
 }
 
-pub fn part_two(input: &str) -> i64 {
+#[allow(clippy::useless_vec)]
+pub fn part_two(input: &str) -> Option<i64> {
     let (sensors, _) = parse_input(input);
 
     // if there's just one coord outside of every sensor's range, that means we don't have to actually scan over 16 million coords: we only have to scan over all the coords immediately adjacent to the ones at the edge of each sensor's range
@@ -190,7 +193,7 @@ pub fn part_two(input: &str) -> i64 {
                 .filter_map(|coord| get_tuning_value_of_coord_if_hidden(*coord, &sensors))
                 .next()
             {
-                return tuning_value;
+                return Some(tuning_value);
             }
         }
     }
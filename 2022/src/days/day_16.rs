@@ -1,6 +1,8 @@
 use regex::Regex;
 use std::collections::HashMap;
 
+advent_of_code::solution!(16);
+
 const MINUTES_UNTIL_ERUPTION: usize = 30;
 const MINUTES_UNTIL_ERUPTION_WITH_ELEPHANT: usize = 26;
 
@@ -12,6 +14,7 @@ struct Valve {
     adjacent_valves: Vec<(String, usize)>,
 }
 
+#[allow(clippy::unnecessary_to_owned)]
 fn parse_input(input: &str) -> (HashMap<String, usize>, Vec<Valve>) {
     let regex_for_line =
         Regex::new(r"Valve (\S\S) has flow rate=(\d+); (?:tunnels lead|tunnel leads) to valves? (\S\S)((?:, \S\S)*)")
@@ -185,21 +188,22 @@ fn part_one_recursively_get_max_pressure_released(
     max_pressure_released
 }
 
-pub fn part_one(input: &str) -> usize {
+pub fn part_one(input: &str) -> Option<usize> {
     println!("be warned that part 1 takes several minutes for the real input");
     let (names_to_indexes, mut valves) = parse_input(input);
     // println!("valves: {:?}", valves);
 
     let mut sub_solutions: HashMap<(usize, Vec<Valve>, usize), usize> = HashMap::new();
 
-    part_one_recursively_get_max_pressure_released(
+    Some(part_one_recursively_get_max_pressure_released(
         MINUTES_UNTIL_ERUPTION,
         &mut valves,
         *names_to_indexes.get("AA").unwrap(),
         &mut sub_solutions,
-    )
+    ))
 }
 
+#[allow(clippy::type_complexity, clippy::useless_vec)]
 fn part_two_recursively_get_max_pressure_released(
     minutes_left: usize,
     valves: &mut Vec<Valve>,
@@ -503,7 +507,8 @@ Second entity is at valve {}
     solution.clone()
 }
 
-pub fn part_two(input: &str) -> usize {
+#[allow(clippy::type_complexity)]
+pub fn part_two(input: &str) -> Option<usize> {
     let (names_to_indexes, mut valves) = parse_input(input);
 
     let mut sub_solutions: HashMap<(usize, Vec<Valve>, [usize; 2]), (usize, String)> =
@@ -520,5 +525,5 @@ pub fn part_two(input: &str) -> usize {
 
     // println!("{}", _report);
 
-    pressure_released
+    Some(pressure_released)
 }
@@ -1,3 +1,5 @@
+advent_of_code::solution!(17);
+
 #[derive(Debug)]
 enum Jet {
     Left,
@@ -22,6 +24,7 @@ impl Rock {
             Rock::Square => 2,
         }
     }
+    #[allow(clippy::ptr_arg)]
     fn fits(&self, x: usize, y: usize, chamber: &Vec<[bool; 7]>) -> bool {
         x + self.width() - 1 < 7
             && match self {
@@ -42,6 +45,7 @@ impl Rock {
             }
     }
 
+    #[allow(clippy::ptr_arg)]
     fn settle_in_chamber(&self, x: usize, y: usize, chamber: &mut Vec<[bool; 7]>) {
         match self {
             Rock::Horizontal => chamber[y][x..x + 4]
@@ -76,6 +80,7 @@ impl Rock {
     }
 }
 
+#[allow(clippy::ptr_arg)]
 fn height_of_tower(chamber: &Vec<[bool; 7]>) -> usize {
     chamber.iter().filter(|row| row.iter().any(|b| *b)).count()
 
@@ -89,6 +94,7 @@ fn height_of_tower(chamber: &Vec<[bool; 7]>) -> usize {
     //     .map(|(index, _)| index + 1)
     //     .unwrap_or(0)
 }
+#[allow(clippy::println_empty_string, clippy::ptr_arg)]
 fn _print_chamber(chamber: &Vec<[bool; 7]>) {
     let tile_as_char = |b: bool| if b { '#' } else { '.' };
     chamber.iter().rev().for_each(|row| {
@@ -103,6 +109,7 @@ fn _print_chamber(chamber: &Vec<[bool; 7]>) {
 
 fn parse_input(input: &str) -> (impl Iterator<Item = Jet> + '_, impl Iterator<Item = Rock>) {
     let jets = input
+        .trim_end()
         .chars()
         .map(|c| match c {
             '<' => Jet::Left,
@@ -124,7 +131,7 @@ fn parse_input(input: &str) -> (impl Iterator<Item = Jet> + '_, impl Iterator<It
     (jets, rocks)
 }
 
-pub fn part_one(input: &str) -> usize {
+pub fn part_one(input: &str) -> Option<usize> {
     let (mut jets, mut rocks) = parse_input(input);
     // println!("{:?}", jets);
 
@@ -174,10 +181,10 @@ pub fn part_one(input: &str) -> usize {
     }
 
     // _print_chamber(&chamber);
-    height_of_tower(&chamber)
+    Some(height_of_tower(&chamber))
 }
 
-pub fn part_two(_input: &str) -> usize {
+pub fn part_two(_input: &str) -> Option<usize> {
     // let (mut jets, mut rocks) = parse_input(_input);
     todo!("I tried discarding excess memory by greedily lopping off the bottom of the tower continuously starting at height 2000 just to see what would happen, but, well 1000000000000 is a big number. The solution must involve identifying a cycle in the ongoing structure of the tower and then doing something analogous to modulo division to find the answer without having to churn through every iteration of rock-laying")
 }
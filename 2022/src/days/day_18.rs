@@ -1,5 +1,7 @@
 use std::collections::HashSet;
 
+advent_of_code::solution!(18);
+
 fn parse_input(input: &str) -> HashSet<(i16, i16, i16)> {
     input.lines().fold(HashSet::new(), |mut acc, line| {
         let mut vals = line.split(",").map(|val| val.parse::<i16>().unwrap());
@@ -20,18 +22,20 @@ fn does_not_exist_to_usize(cube: (i16, i16, i16), cubes: &HashSet<(i16, i16, i16
     }
 }
 
-pub fn part_one(input: &str) -> usize {
+pub fn part_one(input: &str) -> Option<usize> {
     let cubes = parse_input(input);
     // println!("{:?}", cubes);
-    cubes
-        .iter()
-        .map(|cube| {
-            get_adjacent_cubes(*cube)
-                .iter()
-                .map(|ac| does_not_exist_to_usize(*ac, &cubes))
-                .sum::<usize>()
-        })
-        .sum()
+    Some(
+        cubes
+            .iter()
+            .map(|cube| {
+                get_adjacent_cubes(*cube)
+                    .iter()
+                    .map(|ac| does_not_exist_to_usize(*ac, &cubes))
+                    .sum::<usize>()
+            })
+            .sum(),
+    )
 }
 
 fn get_adjacent_cubes(cube: (i16, i16, i16)) -> [(i16, i16, i16); 6] {
@@ -45,6 +49,7 @@ fn get_adjacent_cubes(cube: (i16, i16, i16)) -> [(i16, i16, i16); 6] {
     ]
 }
 
+#[allow(clippy::needless_borrow)]
 fn _does_not_exist_and_is_not_surrounded_to_usize(
     cube: (i16, i16, i16),
     cubes: &HashSet<(i16, i16, i16)>,
@@ -73,7 +78,7 @@ fn _does_not_exist_and_is_not_surrounded_to_usize(
 }
 
 // wrong: 4338 (high)
-pub fn part_two(_input: &str) -> usize {
+pub fn part_two(_input: &str) -> Option<usize> {
     // let cubes = parse_input(input);
     // println!("{:?}", cubes);
 
@@ -16,10 +16,24 @@ pub mod day_15;
 pub mod day_16;
 pub mod day_17;
 pub mod day_18;
-// pub mod day_19;
-// pub mod day_20;
-// pub mod day_21;
-// pub mod day_22;
-// pub mod day_23;
-// pub mod day_24;
-// pub mod day_25;
+
+advent_of_code::days! {
+    day_01,
+    day_02,
+    day_03,
+    day_04,
+    day_05,
+    day_06,
+    day_07,
+    day_08,
+    day_09,
+    day_10,
+    day_11,
+    day_12,
+    day_13,
+    day_14,
+    day_15,
+    day_16,
+    day_17,
+    day_18,
+}
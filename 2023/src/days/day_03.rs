@@ -2,6 +2,7 @@ use std::collections::HashMap;
 
 advent_of_code::solution!(3);
 
+#[allow(clippy::bind_instead_of_map)]
 pub fn part_one(input: &str) -> Option<u32> {
     // to simply avoid double-counting the numbers, I'm going to look for numbers first and surrounding "parts" (symbols) second
     let grid: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
@@ -108,6 +109,11 @@ pub fn part_one(input: &str) -> Option<u32> {
     )
 }
 
+#[allow(
+    clippy::bind_instead_of_map,
+    clippy::into_iter_on_ref,
+    clippy::useless_conversion
+)]
 pub fn part_two(input: &str) -> Option<u32> {
     // let's iterate over the lines in a similar fashion, but this time instead of recording the whole grid first, let's just write down all the numbers with their row and col indices AND all the asterisks with their own row and col indices
     let mut numbers_with_indices: Vec<(usize, usize, u32)> =
@@ -29,6 +29,7 @@ impl Map {
         )
     }
 
+    #[allow(clippy::int_plus_one)]
     fn get_destination(&self, source: u64) -> u64 {
         if let Some(mr) = self.0.iter().find(|mr| {
             source >= mr.source_range_start && source <= mr.source_range_start + mr.range_length - 1
@@ -117,6 +118,7 @@ pub fn part_one(input: &str) -> Option<u64> {
     )
 }
 
+#[allow(clippy::useless_vec)]
 pub fn part_two(input: &str) -> Option<u64> {
     let lines: Vec<&str> = input.lines().collect();
 
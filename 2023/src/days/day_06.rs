@@ -1,5 +1,6 @@
 advent_of_code::solution!(6);
 
+#[allow(clippy::trim_split_whitespace)]
 pub fn part_one(input: &str) -> Option<u64> {
     let mut lines = input.lines();
     let times = lines
@@ -40,6 +41,7 @@ pub fn part_one(input: &str) -> Option<u64> {
     )
 }
 
+#[allow(clippy::neg_multiply)]
 fn solve_quadratic_formula_rounding_inward(
     time: impl Into<f64>,
     distance: impl Into<f64>,
@@ -48,6 +48,7 @@ impl PartOneHand {
     }
 }
 
+#[allow(clippy::redundant_closure)]
 pub fn part_one(input: &str) -> Option<u32> {
     let mut hands: Vec<PartOneHand> = input.lines().map(|line| PartOneHand::new(line)).collect();
     // I could implement the Ord trait for Hand, but converting them to tuples is easier because tuples are automatically sorted lexicographically
@@ -132,6 +133,7 @@ impl PartTwoHand {
     }
 }
 
+#[allow(clippy::redundant_closure)]
 pub fn part_two(input: &str) -> Option<u32> {
     let mut hands: Vec<PartTwoHand> = input.lines().map(|line| PartTwoHand::new(line)).collect();
     // I could implement the Ord trait for Hand, but converting them to tuples is easier because tuples are automatically sorted lexicographically
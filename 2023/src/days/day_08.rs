@@ -49,6 +49,11 @@ pub fn part_one(input: &str) -> Option<u32> {
     Some(steps)
 }
 
+#[allow(
+    clippy::map_clone,
+    clippy::redundant_closure,
+    clippy::useless_conversion
+)]
 pub fn part_two(input: &str) -> Option<u64> {
     let mut lines = input.lines();
 
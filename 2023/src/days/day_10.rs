@@ -1,5 +1,6 @@
 advent_of_code::solution!(10);
 
+#[allow(clippy::unnecessary_lazy_evaluations)]
 pub fn part_one(input: &str) -> Option<u32> {
     // so I really just need to count the number of steps in the loop and then divide by two
     // there are random pipes scattered all over the input, so I can't just count them all
@@ -113,6 +114,7 @@ fn get_next_pipe_indices(
     }
 }
 
+#[allow(clippy::unnecessary_lazy_evaluations)]
 pub fn part_two(input: &str) -> Option<u32> {
     // so I really just need to count the number of steps in the loop and then divide by two
     // there are random pipes scattered all over the input, so I can't just count them all
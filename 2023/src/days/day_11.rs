@@ -5,6 +5,7 @@ struct Point {
     c: usize,
 }
 
+#[allow(clippy::map_flatten)]
 pub fn part_one(input: &str) -> Option<usize> {
     let mut grid: Vec<Vec<bool>> = input
         .lines()
@@ -62,6 +63,11 @@ fn _print_grid(grid: &[Vec<bool>]) {
 }
 
 pub fn part_two(input: &str) -> Option<usize> {
+    Some(sum_of_expanded_distances(input, 1000000))
+}
+
+#[allow(clippy::map_flatten)]
+fn sum_of_expanded_distances(input: &str, empty_space_multiplier: usize) -> usize {
     let grid: Vec<Vec<bool>> = input
         .lines()
         .map(|line| line.chars().map(|c| c == '#').collect())
@@ -88,9 +94,6 @@ pub fn part_two(input: &str) -> Option<usize> {
         .flatten()
         .collect();
 
-    // let empty_space_multiplier = 10;
-    // let empty_space_multiplier = 100;
-    let empty_space_multiplier = 1000000;
     let mut sum_of_shortest_distances_between_pairs = 0;
     for a in 0..points.len() - 1 {
         for b in a + 1..points.len() {
@@ -120,7 +123,7 @@ pub fn part_two(input: &str) -> Option<usize> {
         }
     }
 
-    Some(sum_of_shortest_distances_between_pairs)
+    sum_of_shortest_distances_between_pairs
 }
 
 #[cfg(test)]
@@ -135,12 +138,10 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-
-        //// if the multiplier is 10
-        // assert_eq!(result, Some(1030));
+        let input = advent_of_code::template::read_file("examples", DAY);
 
-        //// if the multiplier is 100
-        assert_eq!(result, Some(8410));
+        // the puzzle only gives example answers for smaller multipliers
+        assert_eq!(sum_of_expanded_distances(&input, 10), 1030);
+        assert_eq!(sum_of_expanded_distances(&input, 100), 8410);
     }
 }
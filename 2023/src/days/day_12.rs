@@ -25,6 +25,7 @@ struct Row {
     contiguous_damaged_group_lengths: Vec<usize>,
 }
 
+#[allow(clippy::match_like_matches_macro, clippy::redundant_closure)]
 pub fn part_one(input: &str) -> Option<u32> {
     let rows: Vec<Row> = input
         .lines()
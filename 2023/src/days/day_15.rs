@@ -4,10 +4,16 @@ fn hash(s: &str) -> usize {
     s.chars().fold(0, |acc, c| (acc + c as usize) * 17 % 256)
 }
 
+#[allow(clippy::redundant_closure)]
 pub fn part_one(input: &str) -> Option<usize> {
     Some(input.trim().split(',').map(|step| hash(step)).sum())
 }
 
+#[allow(
+    clippy::iter_skip_next,
+    clippy::manual_pattern_char_comparison,
+    clippy::needless_borrow
+)]
 pub fn part_two(input: &str) -> Option<usize> {
     let mut lens_boxes: Vec<Option<Vec<(String, usize)>>> = vec![None; 256];
     // I could use a fold here, but I have a hunch that it might be inefficient for each iteration of the fold to keep moving the whole vec from one iteration to the next, over and over
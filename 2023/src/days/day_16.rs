@@ -1,6 +1,6 @@
-advent_of_code::solution!(16);
+advent_of_code::solution!(16, parse);
 
-enum Tile {
+pub(crate) enum Tile {
     Empty,
     Slash,
     Backslash,
@@ -29,12 +29,14 @@ enum Direction {
     Right,
 }
 
-pub fn part_one(input: &str) -> Option<usize> {
-    let grid: Vec<Vec<Tile>> = input
+pub(crate) fn parse(input: &str) -> Vec<Vec<Tile>> {
+    input
         .lines()
         .map(|line| line.chars().map(Tile::new).collect())
-        .collect();
+        .collect()
+}
 
+pub(crate) fn part_one(grid: &[Vec<Tile>]) -> Option<usize> {
     let row_count = grid.len();
     let col_count = grid[0].len();
 
@@ -138,12 +140,7 @@ pub fn part_one(input: &str) -> Option<usize> {
     )
 }
 
-pub fn part_two(input: &str) -> Option<usize> {
-    let grid: Vec<Vec<Tile>> = input
-        .lines()
-        .map(|line| line.chars().map(Tile::new).collect())
-        .collect();
-
+pub(crate) fn part_two(grid: &[Vec<Tile>]) -> Option<usize> {
     let row_count = grid.len();
     let col_count = grid[0].len();
 
@@ -160,7 +157,7 @@ pub fn part_two(input: &str) -> Option<usize> {
             .map(
                 |(starting_direction, starting_row_index, starting_col_index)| {
                     count_energized_tiles(
-                        &grid,
+                        grid,
                         starting_direction,
                         starting_row_index,
                         starting_col_index,
@@ -286,13 +283,17 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&parse(&advent_of_code::template::read_file(
+            "examples", DAY,
+        )));
         assert_eq!(result, Some(46));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&parse(&advent_of_code::template::read_file(
+            "examples", DAY,
+        )));
         assert_eq!(result, Some(51));
     }
 }
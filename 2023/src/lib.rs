@@ -1,3 +1 @@
-pub mod template;
-
-// Use this file to add helper functions and additional modules.
+pub mod days;
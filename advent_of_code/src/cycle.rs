@@ -0,0 +1,268 @@
+/// Cycle detection for puzzles that simulate a process far too many times, e.g. `1_000_000_000` spin cycles.
+///
+/// A process that starts with `initial` and applies `step` over and over produces a sequence of states.
+/// Once a state repeats, so does everything after it, so the state after any number of steps can be looked up.
+use std::{collections::HashMap, hash::Hash};
+
+/// States repeat every `length` steps, starting with the state after `start` steps.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The number of steps after which the same state is reached as after `n` steps, and that is before the cycle repeats.
+    #[must_use]
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+
+    /// The state after `n` steps, only stepping from `initial` as far as [`Cycle::reduce`] requires.
+    #[must_use]
+    pub fn nth<S>(&self, initial: S, mut step: impl FnMut(&S) -> S, n: usize) -> S {
+        (0..self.reduce(n)).fold(initial, |state, _| step(&state))
+    }
+}
+
+/// Finds the cycle with Brent's algorithm, which only keeps two states but steps through the sequence up to three times.
+/// Never returns if no state repeats.
+pub fn brent<S: Clone + Eq>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    Cycle { start, length }
+}
+
+/// The state after `n` steps, finding the cycle with [`brent`].
+/// Never returns if no state repeats.
+pub fn brent_nth<S: Clone + Eq>(initial: S, mut step: impl FnMut(&S) -> S, n: usize) -> S {
+    brent(initial.clone(), &mut step).nth(initial, step, n)
+}
+
+/// Finds the cycle with Floyd's algorithm, which only keeps two states. Usually slower than [`brent`].
+/// Never returns if no state repeats.
+pub fn floyd<S: Clone + Eq>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut tortoise = step(&initial);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        let next = step(&hare);
+        hare = step(&next);
+    }
+
+    let mut start = 0;
+    tortoise = initial;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    let mut length = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        length += 1;
+    }
+
+    Cycle { start, length }
+}
+
+/// The state after `n` steps, finding the cycle with [`floyd`].
+/// Never returns if no state repeats.
+pub fn floyd_nth<S: Clone + Eq>(initial: S, mut step: impl FnMut(&S) -> S, n: usize) -> S {
+    floyd(initial.clone(), &mut step).nth(initial, step, n)
+}
+
+/// The states of a sequence up to and including the first repeated one.
+#[derive(Clone, Debug)]
+pub struct Detected<S> {
+    pub cycle: Cycle,
+    /// The state after each number of steps, up to `cycle.start + cycle.length`.
+    pub states: Vec<S>,
+}
+
+impl<S> Detected<S> {
+    /// The state after `n` steps.
+    #[must_use]
+    pub fn nth(&self, n: usize) -> &S {
+        &self.states[self.cycle.reduce(n)]
+    }
+
+    /// A value after `n` steps that grows by the same amount every cycle, e.g. the height of a tower.
+    /// Only the key of the state passed to [`detect`] has to repeat, the value does not.
+    ///
+    /// # Panics
+    /// If the result overflows `i64`.
+    #[must_use]
+    pub fn extrapolate(&self, n: usize, mut value: impl FnMut(&S) -> i64) -> i64 {
+        let reduced = self.cycle.reduce(n);
+        let growth = value(&self.states[self.cycle.start + self.cycle.length])
+            - value(&self.states[self.cycle.start]);
+        let cycles = i64::try_from((n - reduced) / self.cycle.length).expect("cycles fit into i64");
+
+        cycles
+            .checked_mul(growth)
+            .and_then(|total| total.checked_add(value(&self.states[reduced])))
+            .expect("extrapolated value fits into i64")
+    }
+}
+
+/// Finds the cycle by remembering the key of every state, so states only have to repeat in what `key` returns.
+/// Takes as many steps as the sequence has distinct states and keeps all of them, see [`Detected`].
+/// Never returns if no key repeats.
+pub fn detect<S, K: Eq + Hash>(
+    initial: S,
+    mut step: impl FnMut(&S) -> S,
+    mut key: impl FnMut(&S) -> K,
+) -> Detected<S> {
+    let mut seen = HashMap::from([(key(&initial), 0)]);
+    let mut states = vec![initial];
+
+    loop {
+        let state = step(states.last().expect("there is an initial state"));
+        let index = states.len();
+        states.push(state);
+
+        if let Some(start) = seen.insert(key(&states[index]), index) {
+            return Detected {
+                cycle: Cycle {
+                    start,
+                    length: index - start,
+                },
+                states,
+            };
+        }
+    }
+}
+
+/// The state after `n` steps, skipping over repetitions. Stops early if `n` is reached before a state repeats.
+pub fn nth<S: Clone + Eq + Hash>(initial: S, mut step: impl FnMut(&S) -> S, n: usize) -> S {
+    let mut seen = HashMap::new();
+    let mut states = vec![];
+    let mut state = initial;
+
+    for index in 0..n {
+        if let Some(start) = seen.insert(state.clone(), index) {
+            let cycle = Cycle {
+                start,
+                length: index - start,
+            };
+            return states.swap_remove(cycle.reduce(n));
+        }
+
+        let next = step(&state);
+        states.push(state);
+        state = next;
+    }
+
+    state
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{brent, brent_nth, detect, floyd, floyd_nth, nth, Cycle};
+
+    /// 0 → 1 → 2 → 3 → 4 → 5 → 3 → ...
+    fn step(x: &u32) -> u32 {
+        if *x == 5 {
+            3
+        } else {
+            x + 1
+        }
+    }
+
+    const CYCLE: Cycle = Cycle {
+        start: 3,
+        length: 3,
+    };
+
+    #[test]
+    fn finds_cycles() {
+        assert_eq!(brent(0, step), CYCLE);
+        assert_eq!(floyd(0, step), CYCLE);
+        assert_eq!(detect(0, step, |x| *x).cycle, CYCLE);
+        assert_eq!(
+            brent(7, |x| *x),
+            Cycle {
+                start: 0,
+                length: 1
+            }
+        );
+        assert_eq!(
+            floyd(7, |x| *x),
+            Cycle {
+                start: 0,
+                length: 1
+            }
+        );
+    }
+
+    #[test]
+    fn looks_up_states() {
+        assert_eq!(CYCLE.reduce(2), 2);
+        assert_eq!(CYCLE.reduce(7), 4);
+        assert_eq!(detect(0, step, |x| *x).nth(1_000_000_000_000), &4);
+        assert_eq!(nth(0, step, 1_000_000_000_000), 4);
+        assert_eq!(nth(0, |x| x + 1, 10), 10);
+        assert_eq!(CYCLE.nth(0, step, 7), 4);
+        assert_eq!(brent_nth(0, step, 1_000_000_000_000), 4);
+        assert_eq!(floyd_nth(0, step, 1_000_000_000_000), 4);
+        assert_eq!(brent_nth(0, step, 2), 2);
+    }
+
+    #[test]
+    fn extrapolates_values() {
+        // a tower that grows by 9 every 3 steps once the cycle starts.
+        let detected = detect(
+            (0_u32, 0_i64),
+            |(x, height)| (step(x), height + i64::from(*x) - 1),
+            |(x, _)| *x,
+        );
+
+        let mut state = (0, 0);
+        for _ in 0..100 {
+            state = (step(&state.0), state.1 + i64::from(state.0) - 1);
+        }
+
+        assert_eq!(detected.extrapolate(100, |(_, height)| *height), state.1);
+        assert_eq!(
+            detected.extrapolate(1_000_000_000_000, |(_, height)| *height),
+            2_999_999_999_990
+        );
+    }
+}
+
+/* -------------------------------------------------------------------------- */
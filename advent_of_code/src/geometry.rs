@@ -0,0 +1,426 @@
+/// Points and directions on a plane or in space, shared by solutions and the grid.
+///
+/// Points follow the orientation of puzzle inputs: `x` grows to the right and `y` grows downwards,
+/// so north is towards smaller `y`.
+use std::{
+    fmt::Display,
+    ops::{Add, AddAssign, Sub, SubAssign},
+};
+
+/// An integer type that points can be made of.
+pub trait Coordinate:
+    Copy + Ord + Default + Display + Add<Output = Self> + Sub<Output = Self>
+{
+    const ONE: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+
+    /// Moves by `-1`, `0` or `1`, returns `None` if the result does not fit.
+    #[must_use]
+    fn checked_step(self, delta: isize) -> Option<Self> {
+        match delta.signum() {
+            -1 => self.checked_sub(Self::ONE),
+            1 => self.checked_add(Self::ONE),
+            _ => Some(self),
+        }
+    }
+
+    /// The absolute difference, which does not overflow for unsigned types.
+    #[must_use]
+    fn distance(self, other: Self) -> Self {
+        if self > other {
+            self - other
+        } else {
+            other - self
+        }
+    }
+}
+
+macro_rules! coordinate {
+    ($($t:ty),*) => {
+        $(
+            impl Coordinate for $t {
+                const ONE: Self = 1;
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$t>::checked_add(self, other)
+                }
+
+                fn checked_sub(self, other: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, other)
+                }
+            }
+        )*
+    };
+}
+
+coordinate!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/* -------------------------------------------------------------------------- */
+
+/// One of the four orthogonal directions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction4 {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction4 {
+    /// All directions, clockwise starting with north.
+    pub const ALL: [Direction4; 4] = [
+        Direction4::North,
+        Direction4::East,
+        Direction4::South,
+        Direction4::West,
+    ];
+
+    /// The direction after a quarter turn clockwise.
+    #[must_use]
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    /// The direction after a quarter turn counterclockwise.
+    #[must_use]
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    #[must_use]
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    /// The step in this direction as `(dx, dy)`.
+    #[must_use]
+    pub fn offset(self) -> (isize, isize) {
+        Direction8::from(self).offset()
+    }
+}
+
+/// Reads arrows (`^>v<`) and initials (`NESW` or `URDL`), as used in puzzle inputs.
+impl TryFrom<char> for Direction4 {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '^' | 'N' | 'U' => Ok(Direction4::North),
+            '>' | 'E' | 'R' => Ok(Direction4::East),
+            'v' | 'S' | 'D' => Ok(Direction4::South),
+            '<' | 'W' | 'L' => Ok(Direction4::West),
+            _ => Err(format!("`{value}` is not a direction.")),
+        }
+    }
+}
+
+/// One of the four orthogonal or four diagonal directions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction8 {
+    /// All directions, clockwise starting with north.
+    pub const ALL: [Direction8; 8] = [
+        Direction8::North,
+        Direction8::NorthEast,
+        Direction8::East,
+        Direction8::SouthEast,
+        Direction8::South,
+        Direction8::SouthWest,
+        Direction8::West,
+        Direction8::NorthWest,
+    ];
+
+    /// The direction after an eighth turn clockwise.
+    #[must_use]
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// The direction after an eighth turn counterclockwise.
+    #[must_use]
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    #[must_use]
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    #[must_use]
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+
+    /// The step in this direction as `(dx, dy)`.
+    #[must_use]
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Direction8::North => (0, -1),
+            Direction8::NorthEast => (1, -1),
+            Direction8::East => (1, 0),
+            Direction8::SouthEast => (1, 1),
+            Direction8::South => (0, 1),
+            Direction8::SouthWest => (-1, 1),
+            Direction8::West => (-1, 0),
+            Direction8::NorthWest => (-1, -1),
+        }
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(value: Direction4) -> Self {
+        match value {
+            Direction4::North => Direction8::North,
+            Direction4::East => Direction8::East,
+            Direction4::South => Direction8::South,
+            Direction4::West => Direction8::West,
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A point on a plane.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: Coordinate> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Point2 { x, y }
+    }
+
+    #[must_use]
+    pub fn manhattan(self, other: Self) -> T {
+        self.x.distance(other.x) + self.y.distance(other.y)
+    }
+
+    /// The neighboring point in a direction, or `None` if it does not fit into `T`.
+    #[must_use]
+    pub fn checked_step(self, direction: impl Into<Direction8>) -> Option<Self> {
+        let (dx, dy) = direction.into().offset();
+        Some(Point2::new(
+            self.x.checked_step(dx)?,
+            self.y.checked_step(dy)?,
+        ))
+    }
+
+    /// The orthogonal neighbors that fit into `T`, clockwise starting with the one to the north.
+    pub fn neighbors_4(self) -> impl Iterator<Item = Self> {
+        Direction4::ALL
+            .into_iter()
+            .filter_map(move |direction| self.checked_step(direction))
+    }
+
+    /// The orthogonal and diagonal neighbors that fit into `T`, clockwise starting with the one to the north.
+    pub fn neighbors_8(self) -> impl Iterator<Item = Self> {
+        Direction8::ALL
+            .into_iter()
+            .filter_map(move |direction| self.checked_step(direction))
+    }
+}
+
+impl<T: Coordinate> Add for Point2<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Point2::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Coordinate> Sub for Point2<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Point2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Coordinate> AddAssign for Point2<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Coordinate> SubAssign for Point2<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+// Adding a direction moves a point by one step. Like any other overflow, a step that does not fit into `T` panics,
+// see `Point2::checked_step`.
+macro_rules! step {
+    ($($direction:ty),*) => {
+        $(
+            impl<T: Coordinate> Add<$direction> for Point2<T> {
+                type Output = Self;
+
+                fn add(self, rhs: $direction) -> Self::Output {
+                    self.checked_step(rhs)
+                        .unwrap_or_else(|| panic!("stepping {rhs:?} from {self} overflows."))
+                }
+            }
+
+            impl<T: Coordinate> AddAssign<$direction> for Point2<T> {
+                fn add_assign(&mut self, rhs: $direction) {
+                    *self = *self + rhs;
+                }
+            }
+        )*
+    };
+}
+
+step!(Direction4, Direction8);
+
+impl<T: Display> Display for Point2<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A point in space.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Coordinate> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Point3 { x, y, z }
+    }
+
+    #[must_use]
+    pub fn manhattan(self, other: Self) -> T {
+        self.x.distance(other.x) + self.y.distance(other.y) + self.z.distance(other.z)
+    }
+
+    /// The neighbors sharing a face, i.e. one step along each axis, that fit into `T`.
+    pub fn neighbors_6(self) -> impl Iterator<Item = Self> {
+        [
+            (-1, 0, 0),
+            (1, 0, 0),
+            (0, -1, 0),
+            (0, 1, 0),
+            (0, 0, -1),
+            (0, 0, 1),
+        ]
+        .into_iter()
+        .filter_map(move |(dx, dy, dz)| {
+            Some(Point3::new(
+                self.x.checked_step(dx)?,
+                self.y.checked_step(dy)?,
+                self.z.checked_step(dz)?,
+            ))
+        })
+    }
+}
+
+impl<T: Coordinate> Add for Point3<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Point3::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl<T: Coordinate> Sub for Point3<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Point3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl<T: Coordinate> AddAssign for Point3<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Coordinate> SubAssign for Point3<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Display> Display for Point3<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Direction4, Direction8, Point2, Point3};
+
+    #[test]
+    fn turns_directions() {
+        assert_eq!(Direction4::North.turn_right(), Direction4::East);
+        assert_eq!(Direction4::North.turn_left(), Direction4::West);
+        assert_eq!(Direction4::West.reverse(), Direction4::East);
+        assert_eq!(Direction8::North.turn_left(), Direction8::NorthWest);
+        assert_eq!(Direction8::NorthEast.reverse(), Direction8::SouthWest);
+        assert!(Direction8::SouthEast.is_diagonal());
+        assert_eq!(Direction4::try_from('v'), Ok(Direction4::South));
+        assert_eq!(Direction4::try_from('L'), Ok(Direction4::West));
+        assert!(Direction4::try_from('x').is_err());
+    }
+
+    #[test]
+    fn moves_points() {
+        let mut point = Point2::new(3_i32, 4);
+        point += Direction4::North;
+        assert_eq!(point, Point2::new(3, 3));
+        assert_eq!(point + Direction8::SouthWest, Point2::new(2, 4));
+        assert_eq!(point - Point2::new(1, 1), Point2::new(2, 2));
+        assert_eq!(Point2::new(0_usize, 0).checked_step(Direction4::West), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_on_overflowing_steps() {
+        let _ = Point2::new(0_u8, 0) + Direction4::North;
+    }
+
+    #[test]
+    fn measures_manhattan_distances() {
+        assert_eq!(Point2::new(1_u32, 5).manhattan(Point2::new(4, 1)), 7);
+        assert_eq!(Point2::new(-2_i64, 3).manhattan(Point2::new(2, -3)), 10);
+        assert_eq!(Point3::new(1_i32, 2, 3).manhattan(Point3::new(-1, 0, 0)), 7);
+    }
+
+    #[test]
+    fn finds_neighbors() {
+        assert_eq!(
+            Point2::new(0_usize, 0).neighbors_4().collect::<Vec<_>>(),
+            vec![Point2::new(1, 0), Point2::new(0, 1)]
+        );
+        assert_eq!(Point2::new(1_i32, 1).neighbors_8().count(), 8);
+        assert_eq!(Point3::new(0_u8, 5, 5).neighbors_6().count(), 5);
+        assert_eq!(Point3::new(1, 2, 3).to_string(), "(1, 2, 3)");
+    }
+}
+
+/* -------------------------------------------------------------------------- */
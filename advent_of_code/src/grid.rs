@@ -0,0 +1,521 @@
+/// A two-dimensional grid of cells, as found in many puzzle inputs.
+use std::{
+    error::Error,
+    fmt::Display,
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+use crate::geometry::{Direction4, Direction8, Point2};
+
+/// A position in a grid as `(row, column)`, starting at the top left.
+/// Methods that take a position also accept a [`Point2`], with `x` as the column and `y` as the row.
+pub type Position = (usize, usize);
+
+/// Cells are stored row by row in a single `Vec`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+/// A line of the input that is not as long as the first one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GridError {
+    /// The line number, starting at 1.
+    pub line: usize,
+    pub expected: usize,
+    pub found: usize,
+}
+
+impl Error for GridError {}
+
+impl Display for GridError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {} has {} cells, expected {} like the first line.",
+            self.line, self.found, self.expected
+        )
+    }
+}
+
+impl<T> Grid<T> {
+    /// Parses a grid with a cell per character, mapping each character with `cell`.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> T) -> Result<Self, GridError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+
+        for (index, line) in input.lines().enumerate() {
+            let len_before = cells.len();
+            cells.extend(line.chars().map(&mut cell));
+            let found = cells.len() - len_before;
+
+            let expected = *width.get_or_insert(found);
+            if found != expected {
+                return Err(GridError {
+                    line: index + 1,
+                    expected,
+                    found,
+                });
+            }
+            height += 1;
+        }
+
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    /// Creates a grid from cells stored row by row.
+    ///
+    /// # Panics
+    /// If the number of cells is not a multiple of `width`.
+    #[must_use]
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Self {
+        assert!(
+            width == 0 && cells.is_empty() || width > 0 && cells.len().is_multiple_of(width),
+            "{} cells don't fill rows of {width}.",
+            cells.len()
+        );
+
+        Grid {
+            width,
+            height: cells.len().checked_div(width).unwrap_or(0),
+            cells,
+        }
+    }
+
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    #[must_use]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    #[must_use]
+    pub fn contains(&self, position: impl Into<Position>) -> bool {
+        let (row, col) = position.into();
+        row < self.height && col < self.width
+    }
+
+    #[must_use]
+    pub fn get(&self, position: impl Into<Position>) -> Option<&T> {
+        let position = position.into();
+        self.contains(position)
+            .then(|| &self.cells[self.index_of(position)])
+    }
+
+    pub fn get_mut(&mut self, position: impl Into<Position>) -> Option<&mut T> {
+        let position = position.into();
+        if self.contains(position) {
+            let index = self.index_of(position);
+            Some(&mut self.cells[index])
+        } else {
+            None
+        }
+    }
+
+    /// The cells of a row, from left to right.
+    ///
+    /// # Panics
+    /// If the row is out of bounds.
+    #[must_use]
+    pub fn row(&self, row: usize) -> &[T] {
+        assert!(row < self.height, "row {row} is out of bounds.");
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on a chunk size of 0, so empty grids yield no rows.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The cells of a column, from top to bottom.
+    ///
+    /// # Panics
+    /// If the column is out of bounds.
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.width, "column {col} is out of bounds.");
+        self.cells.iter().skip(col).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    /// All positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    /// All cells with their position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Position, &mut T)> {
+        let width = self.width;
+        self.cells
+            .iter_mut()
+            .enumerate()
+            .map(move |(index, cell)| ((index / width, index % width), cell))
+    }
+
+    /// The position of the first cell, row by row, that matches the predicate.
+    pub fn find(&self, predicate: impl FnMut(&T) -> bool) -> Option<Position> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|index| (index / self.width, index % self.width))
+    }
+
+    /// The position at an offset from another one, or `None` if it's outside of the grid.
+    #[must_use]
+    pub fn offset(
+        &self,
+        position: impl Into<Position>,
+        (d_row, d_col): (isize, isize),
+    ) -> Option<Position> {
+        let (row, col) = position.into();
+        let position = (
+            row.checked_add_signed(d_row)?,
+            col.checked_add_signed(d_col)?,
+        );
+        self.contains(position).then_some(position)
+    }
+
+    /// The position at an offset from another one, wrapping around the edges of the grid.
+    #[must_use]
+    pub fn wrapping_offset(
+        &self,
+        position: impl Into<Position>,
+        (d_row, d_col): (isize, isize),
+    ) -> Position {
+        let (row, col) = position.into();
+        (wrap(row, d_row, self.height), wrap(col, d_col, self.width))
+    }
+
+    /// The neighboring position in a direction, or `None` if it's outside of the grid. North is up.
+    #[must_use]
+    pub fn step(
+        &self,
+        position: impl Into<Position>,
+        direction: impl Into<Direction8>,
+    ) -> Option<Position> {
+        self.offset(position, row_col_offset(direction))
+    }
+
+    /// The neighboring position in a direction, wrapping around the edges of the grid.
+    #[must_use]
+    pub fn wrapping_step(
+        &self,
+        position: impl Into<Position>,
+        direction: impl Into<Direction8>,
+    ) -> Position {
+        self.wrapping_offset(position, row_col_offset(direction))
+    }
+
+    /// The orthogonal neighbors of a cell that are inside of the grid, clockwise starting with the one above.
+    pub fn neighbors_4(
+        &self,
+        position: impl Into<Position>,
+    ) -> impl Iterator<Item = Position> + '_ {
+        let position = position.into();
+        Direction4::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction))
+    }
+
+    /// The orthogonal and diagonal neighbors of a cell that are inside of the grid.
+    pub fn neighbors_8(
+        &self,
+        position: impl Into<Position>,
+    ) -> impl Iterator<Item = Position> + '_ {
+        let position = position.into();
+        Direction8::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction))
+    }
+
+    /// The orthogonal neighbors of a cell, wrapping around the edges of the grid.
+    pub fn wrapping_neighbors_4(
+        &self,
+        position: impl Into<Position>,
+    ) -> impl Iterator<Item = Position> + '_ {
+        let position = position.into();
+        Direction4::ALL
+            .into_iter()
+            .map(move |direction| self.wrapping_step(position, direction))
+    }
+
+    /// The orthogonal and diagonal neighbors of a cell, wrapping around the edges of the grid.
+    pub fn wrapping_neighbors_8(
+        &self,
+        position: impl Into<Position>,
+    ) -> impl Iterator<Item = Position> + '_ {
+        let position = position.into();
+        Direction8::ALL
+            .into_iter()
+            .map(move |direction| self.wrapping_step(position, direction))
+    }
+
+    /// Maps every cell, keeping the shape of the grid.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    fn index_of(&self, (row, col): Position) -> usize {
+        row * self.width + col
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Creates a grid with every cell set to `value`.
+    #[must_use]
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Mirrors the grid along its main diagonal, so rows become columns.
+    #[must_use]
+    pub fn transpose(&self) -> Self {
+        self.rearrange(self.height, self.width, |(row, col)| (col, row))
+    }
+
+    /// Rotates the grid by a quarter turn clockwise.
+    #[must_use]
+    pub fn rotate_clockwise(&self) -> Self {
+        self.rearrange(self.height, self.width, |(row, col)| {
+            (self.height - 1 - col, row)
+        })
+    }
+
+    /// Rotates the grid by a quarter turn counterclockwise.
+    #[must_use]
+    pub fn rotate_counterclockwise(&self) -> Self {
+        self.rearrange(self.height, self.width, |(row, col)| {
+            (col, self.width - 1 - row)
+        })
+    }
+
+    /// Builds a grid of the given size, taking each cell from the position `source` maps it to.
+    fn rearrange(
+        &self,
+        width: usize,
+        height: usize,
+        source: impl Fn(Position) -> Position,
+    ) -> Self {
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |col| (row, col)))
+            .map(|position| self[source(position)].clone())
+            .collect();
+
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+}
+
+/// Directions step in `(dx, dy)`, positions are `(row, column)`.
+fn row_col_offset(direction: impl Into<Direction8>) -> (isize, isize) {
+    let (dx, dy) = direction.into().offset();
+    (dy, dx)
+}
+
+fn wrap(value: usize, offset: isize, len: usize) -> usize {
+    let len = isize::try_from(len).expect("grid dimensions fit into isize");
+    let value = isize::try_from(value).expect("grid dimensions fit into isize");
+    (value + offset).rem_euclid(len).unsigned_abs()
+}
+
+impl<T, P: Into<Position>> Index<P> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: P) -> &Self::Output {
+        let position = position.into();
+        self.get(position)
+            .unwrap_or_else(|| panic!("position {position:?} is out of bounds."))
+    }
+}
+
+impl<T, P: Into<Position>> IndexMut<P> for Grid<T> {
+    fn index_mut(&mut self, position: P) -> &mut Self::Output {
+        let position = position.into();
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("position {position:?} is out of bounds."))
+    }
+}
+
+/// Points are positions with `x` as the column and `y` as the row.
+impl From<Point2<usize>> for Position {
+    fn from(point: Point2<usize>) -> Self {
+        (point.y, point.x)
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = GridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::parse(s, |c| c)
+    }
+}
+
+/// Prints a row per line, e.g. to debug a `Grid<char>`.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (index, row) in self.rows().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Grid, GridError};
+    use crate::geometry::{Direction4, Direction8, Point2};
+
+    fn example() -> Grid<char> {
+        "abc\ndef".parse().unwrap()
+    }
+
+    #[test]
+    fn parses_grids() {
+        let grid = example();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 0)], 'd');
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.get((0, 3)), None);
+        assert_eq!(grid.to_string(), "abc\ndef");
+
+        let digits = Grid::parse("12\r\n34\r\n", |c| c.to_digit(10).unwrap()).unwrap();
+        assert_eq!(digits.row(1), &[3, 4]);
+        assert!(Grid::parse("", |c| c).unwrap().is_empty());
+    }
+
+    #[test]
+    fn rejects_ragged_rows() {
+        assert_eq!(
+            "abc\nde\n".parse::<Grid<char>>(),
+            Err(GridError {
+                line: 2,
+                expected: 3,
+                found: 2
+            })
+        );
+    }
+
+    #[test]
+    fn views_rows_and_columns() {
+        let grid = example();
+
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            vec![&['a', 'b', 'c'], &['d', 'e', 'f']]
+        );
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(
+            grid.columns()
+                .map(Iterator::collect)
+                .collect::<Vec<String>>(),
+            vec!["ad", "be", "cf"]
+        );
+        assert_eq!(grid.find(|c| *c == 'e'), Some((1, 1)));
+        assert_eq!(grid.iter().nth(4), Some(((1, 1), &'e')));
+    }
+
+    #[test]
+    fn finds_neighbors() {
+        let grid = example();
+
+        assert_eq!(
+            grid.neighbors_4((0, 0)).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 0)]
+        );
+        assert_eq!(grid.neighbors_8((0, 1)).count(), 5);
+        assert_eq!(
+            grid.wrapping_neighbors_4((0, 0)).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1), (1, 0), (0, 2)]
+        );
+        assert_eq!(grid.wrapping_neighbors_8((1, 2)).count(), 8);
+        assert_eq!(grid.step((0, 1), Direction4::South), Some((1, 1)));
+        assert_eq!(grid.step((0, 1), Direction8::NorthEast), None);
+        assert_eq!(grid.wrapping_step((0, 2), Direction4::East), (0, 0));
+        assert_eq!(grid[Point2::new(2, 1)], 'f');
+    }
+
+    #[test]
+    fn accepts_points() {
+        let grid = example();
+        let point = Point2::new(2_usize, 0);
+
+        assert!(grid.contains(point));
+        assert_eq!(grid.get(point), Some(&'c'));
+        assert_eq!(grid.get(Point2::new(0_usize, 2)), None);
+        assert_eq!(grid.step(point, Direction4::South), Some((1, 2)));
+        assert_eq!(
+            grid.neighbors_4(point).collect::<Vec<_>>(),
+            grid.neighbors_4((0, 2)).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn rotates_and_transposes() {
+        let grid = example();
+
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+    }
+
+    #[test]
+    fn updates_cells() {
+        let mut grid = Grid::new(2, 2, 0);
+        grid[(0, 1)] = 1;
+        *grid.get_mut((1, 1)).unwrap() = 2;
+        for (position, cell) in grid.iter_mut() {
+            if position == (1, 0) {
+                *cell = 3;
+            }
+        }
+
+        assert_eq!(grid.to_string(), "01\n32");
+        assert_eq!(grid.map(|c| c * 2).to_string(), "02\n64");
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,272 @@
+/// Helpers for the usual shapes of puzzle inputs. Errors point at the line and column that could not be parsed.
+use std::{any::type_name, error::Error, fmt::Display, str::FromStr};
+
+/// An error at a position of the input, both starting at 1. Columns count characters, not bytes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl Error for ParseError {}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+/// A line of the input along with its number, so errors can point at it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Line<'a> {
+    /// The line number, starting at 1.
+    pub number: usize,
+    /// The text of the line, without the line break.
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// An error at a byte offset of the line.
+    #[must_use]
+    pub fn error(&self, offset: usize, message: impl Into<String>) -> ParseError {
+        ParseError {
+            line: self.number,
+            column: self.text[..offset].chars().count() + 1,
+            message: message.into(),
+        }
+    }
+
+    /// All integers in the line, e.g. `[3, -2, 10]` for `x=3, y=-2..10`.
+    /// A `-` is only a sign if it does not follow a letter or digit, so `1-3` holds `1` and `3`.
+    pub fn integers<T: FromStr>(&self) -> Result<Vec<T>, ParseError> {
+        integer_spans(self.text)
+            .map(|(start, end)| {
+                let token = &self.text[start..end];
+                token.parse().map_err(|_| {
+                    self.error(
+                        start,
+                        format!("`{token}` is not a valid {}.", type_name::<T>()),
+                    )
+                })
+            })
+            .collect()
+    }
+
+    /// Splits a `key: value` line at the first `separator`, e.g. `("Game 1", "3 blue")` for `Game 1: 3 blue`.
+    pub fn key_value(&self, separator: &str) -> Result<(&'a str, &'a str), ParseError> {
+        self.text
+            .split_once(separator)
+            .ok_or_else(|| self.error(self.text.len(), format!("expected `{separator}`.")))
+    }
+
+    /// The rest of the line after `prefix`, e.g. `" 7 15 30"` for `Time: 7 15 30` and `Time:`.
+    pub fn strip_prefix(&self, prefix: &str) -> Result<&'a str, ParseError> {
+        self.text
+            .strip_prefix(prefix)
+            .ok_or_else(|| self.error(0, format!("expected `{prefix}`.")))
+    }
+
+    /// Splits the line into columns of the given widths in characters.
+    /// Columns past the end of the line are empty, as editors tend to strip trailing whitespace,
+    /// but text after the last column is an error.
+    pub fn columns(&self, widths: &[usize]) -> Result<Vec<&'a str>, ParseError> {
+        let mut rest = self.text;
+        let mut offset = 0;
+        let mut columns = Vec::with_capacity(widths.len());
+
+        for width in widths {
+            let len = rest
+                .char_indices()
+                .nth(*width)
+                .map_or(rest.len(), |(index, _)| index);
+            columns.push(&rest[..len]);
+            rest = &rest[len..];
+            offset += len;
+        }
+
+        if rest.is_empty() {
+            Ok(columns)
+        } else {
+            Err(self.error(offset, "unexpected text after the last column."))
+        }
+    }
+}
+
+/// The lines of the input. Both `\n` and `\r\n` line breaks are accepted.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(index, text)| Line {
+        number: index + 1,
+        text,
+    })
+}
+
+/// Groups the lines of the input into blocks separated by blank lines, e.g. the sections of an input.
+/// Lines that only hold whitespace count as blank, and several blank lines in a row count as one.
+#[must_use]
+pub fn blocks(input: &str) -> Vec<Vec<Line<'_>>> {
+    let mut blocks = vec![];
+    let mut block = vec![];
+
+    for line in lines(input) {
+        if line.text.trim().is_empty() {
+            if !block.is_empty() {
+                blocks.push(block);
+                block = vec![];
+            }
+        } else {
+            block.push(line);
+        }
+    }
+
+    if !block.is_empty() {
+        blocks.push(block);
+    }
+    blocks
+}
+
+/// All integers in the input, line by line, see [`Line::integers`].
+pub fn integers<T: FromStr>(input: &str) -> Result<Vec<T>, ParseError> {
+    let mut integers = vec![];
+    for line in lines(input) {
+        integers.extend(line.integers()?);
+    }
+    Ok(integers)
+}
+
+/// Splits every line into a key and a value, see [`Line::key_value`].
+pub fn records<'a>(
+    lines: impl IntoIterator<Item = Line<'a>>,
+    separator: &str,
+) -> Result<Vec<(&'a str, &'a str)>, ParseError> {
+    lines
+        .into_iter()
+        .map(|line| line.key_value(separator))
+        .collect()
+}
+
+/// The byte ranges of the integers in a text, including their sign.
+fn integer_spans(text: &str) -> impl Iterator<Item = (usize, usize)> + '_ {
+    let bytes = text.as_bytes();
+    let mut index = 0;
+
+    std::iter::from_fn(move || {
+        while index < bytes.len() {
+            let is_sign = bytes[index] == b'-'
+                && bytes.get(index + 1).is_some_and(u8::is_ascii_digit)
+                && (index == 0 || !bytes[index - 1].is_ascii_alphanumeric());
+
+            if is_sign || bytes[index].is_ascii_digit() {
+                let start = index;
+                index += 1;
+                while index < bytes.len() && bytes[index].is_ascii_digit() {
+                    index += 1;
+                }
+                return Some((start, index));
+            }
+            index += 1;
+        }
+        None
+    })
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{blocks, integers, lines, records, Line, ParseError};
+
+    fn line(text: &str) -> Line<'_> {
+        Line { number: 3, text }
+    }
+
+    #[test]
+    fn extracts_integers() {
+        assert_eq!(
+            line("Sensor at x=2, y=-18: beacon at x=-2, y=15").integers::<i32>(),
+            Ok(vec![2, -18, -2, 15])
+        );
+        assert_eq!(line("2-4,6-8").integers::<u8>(), Ok(vec![2, 4, 6, 8]));
+        assert_eq!(
+            line("move 1 from 2 to 1").integers::<usize>(),
+            Ok(vec![1, 2, 1])
+        );
+        assert_eq!(line("no numbers - here").integers::<i64>(), Ok(vec![]));
+        assert_eq!(integers::<u32>("1\r\n\r\n22 333\n"), Ok(vec![1, 22, 333]));
+    }
+
+    #[test]
+    fn reports_positions() {
+        assert_eq!(
+            line("ab 1 300").integers::<u8>(),
+            Err(ParseError {
+                line: 3,
+                column: 6,
+                message: "`300` is not a valid u8.".into()
+            })
+        );
+        assert_eq!(
+            integers::<u32>("1\nx -2").unwrap_err().to_string(),
+            "line 2, column 3: `-2` is not a valid u32."
+        );
+        assert_eq!(line("é 1").error(2, "oops").column, 2);
+    }
+
+    #[test]
+    fn splits_blocks() {
+        let blocks = blocks("1\r\n2\r\n\r\n3\n\n \n4\n");
+
+        assert_eq!(blocks.len(), 3);
+        assert_eq!(
+            blocks[0].iter().map(|l| l.text).collect::<Vec<_>>(),
+            vec!["1", "2"]
+        );
+        assert_eq!(
+            blocks[2][0],
+            Line {
+                number: 7,
+                text: "4"
+            }
+        );
+        assert!(super::blocks("\n\n").is_empty());
+    }
+
+    #[test]
+    fn parses_records() {
+        let input = "Time:      7  15   30\nDistance:  9  40  200";
+
+        assert_eq!(
+            records(lines(input), ":").unwrap(),
+            vec![("Time", "      7  15   30"), ("Distance", "  9  40  200")]
+        );
+        assert_eq!(
+            line("Game 1: 3 blue").key_value(": "),
+            Ok(("Game 1", "3 blue"))
+        );
+        assert_eq!(
+            line("Game 1 3 blue").key_value(": ").unwrap_err().column,
+            14
+        );
+        assert_eq!(line("Time: 7").strip_prefix("Time:"), Ok(" 7"));
+        assert!(line("Distance: 9").strip_prefix("Time:").is_err());
+    }
+
+    #[test]
+    fn splits_columns() {
+        assert_eq!(
+            line("[Z] [M] [P]").columns(&[4, 4, 3]),
+            Ok(vec!["[Z] ", "[M] ", "[P]"])
+        );
+        assert_eq!(
+            line("    [D]").columns(&[4, 4, 3]),
+            Ok(vec!["    ", "[D]", ""])
+        );
+        assert_eq!(line("abcdef").columns(&[2, 2]).unwrap_err().column, 5);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,419 @@
+/// Graph searches over a successor closure, so a solution only has to describe how to get from one node to the next.
+///
+/// Nodes are stored once in an arena and referred to by index, so they are only hashed when discovered
+/// and only cloned when a path is returned.
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// A cost of an edge, e.g. `u32`. `Default` is expected to be zero.
+pub trait Cost: Copy + Ord + Default + Add<Output = Self> {}
+
+impl<T: Copy + Ord + Default + Add<Output = T>> Cost for T {}
+
+/// A path from the start to a goal, including both.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Path<N, C> {
+    pub nodes: Vec<N>,
+    pub cost: C,
+}
+
+/// Finds a path with the fewest steps from `start` to a node that satisfies `is_goal`.
+pub fn bfs<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, usize>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut arena = Arena::new(start);
+    let mut depths = vec![0];
+    let mut queue = VecDeque::from([0]);
+
+    while let Some(index) = queue.pop_front() {
+        if is_goal(&arena.nodes[index]) {
+            return Some(Path {
+                nodes: arena.path(index),
+                cost: depths[index],
+            });
+        }
+
+        for next in successors(&arena.nodes[index]) {
+            if let Some(next_index) = arena.insert(next, index) {
+                depths.push(depths[index] + 1);
+                queue.push_back(next_index);
+            }
+        }
+    }
+
+    None
+}
+
+/// The number of steps from `start` to every node that can be reached.
+pub fn bfs_distances<N, I>(start: N, mut successors: impl FnMut(&N) -> I) -> HashMap<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut arena = Arena::new(start);
+    let mut depths = vec![0];
+    let mut queue = VecDeque::from([0]);
+
+    while let Some(index) = queue.pop_front() {
+        for next in successors(&arena.nodes[index]) {
+            if let Some(next_index) = arena.insert(next, index) {
+                depths.push(depths[index] + 1);
+                queue.push_back(next_index);
+            }
+        }
+    }
+
+    arena.nodes.into_iter().zip(depths).collect()
+}
+
+/// Finds a cheapest path from `start` to a node that satisfies `is_goal`.
+/// `successors` returns the neighbors of a node along with the cost of getting there.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+/// The cost of a cheapest path from `start` to every node that can be reached.
+pub fn dijkstra_distances<N, C, I>(start: N, successors: impl FnMut(&N) -> I) -> HashMap<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let search = explore(start, successors, |_| C::default(), |_| false);
+    search.arena.nodes.into_iter().zip(search.costs).collect()
+}
+
+/// Finds a cheapest path like [`dijkstra`], visiting nodes that `heuristic` estimates to be closer to a goal first.
+/// The path is only guaranteed to be the cheapest if the heuristic never overestimates the remaining cost.
+pub fn astar<N, C, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    heuristic: impl FnMut(&N) -> C,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let search = explore(start, successors, heuristic, is_goal);
+    search.goal.map(|index| Path {
+        nodes: search.arena.path(index),
+        cost: search.costs[index],
+    })
+}
+
+/// All nodes that can be reached from any of the `starts`, including them.
+pub fn flood_fill<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+) -> HashSet<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut stack: Vec<N> = starts.into_iter().collect();
+    let mut visited: HashSet<N> = stack.iter().cloned().collect();
+
+    while let Some(node) = stack.pop() {
+        for next in successors(&node) {
+            if !visited.contains(&next) {
+                visited.insert(next.clone());
+                stack.push(next);
+            }
+        }
+    }
+
+    visited
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Every node discovered by a search, along with the index of the node it was discovered from.
+struct Arena<N> {
+    nodes: Vec<N>,
+    indices: HashMap<N, usize>,
+    parents: Vec<usize>,
+}
+
+impl<N: Clone + Eq + Hash> Arena<N> {
+    fn new(start: N) -> Self {
+        Arena {
+            indices: HashMap::from([(start.clone(), 0)]),
+            nodes: vec![start],
+            parents: vec![0],
+        }
+    }
+
+    fn get(&self, node: &N) -> Option<usize> {
+        self.indices.get(node).copied()
+    }
+
+    /// Adds a node unless it was discovered before, returns its index if it's new.
+    fn insert(&mut self, node: N, parent: usize) -> Option<usize> {
+        if self.indices.contains_key(&node) {
+            return None;
+        }
+
+        let index = self.nodes.len();
+        self.indices.insert(node.clone(), index);
+        self.nodes.push(node);
+        self.parents.push(parent);
+        Some(index)
+    }
+
+    /// The nodes from the start to the node at `index`.
+    fn path(&self, mut index: usize) -> Vec<N> {
+        let mut path = vec![self.nodes[index].clone()];
+        while index != 0 {
+            index = self.parents[index];
+            path.push(self.nodes[index].clone());
+        }
+        path.reverse();
+        path
+    }
+}
+
+/// An entry of the open set, ordered so the `BinaryHeap` pops the lowest estimated total cost first.
+struct Entry<C> {
+    estimate: C,
+    cost: C,
+    index: usize,
+}
+
+impl<C: Ord> Ord for Entry<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .estimate
+            .cmp(&self.estimate)
+            // on ties, prefer nodes that are further along, which are likely closer to the goal.
+            .then_with(|| self.cost.cmp(&other.cost))
+    }
+}
+
+impl<C: Ord> PartialOrd for Entry<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C: Ord> PartialEq for Entry<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<C: Ord> Eq for Entry<C> {}
+
+struct Explored<N, C> {
+    arena: Arena<N>,
+    costs: Vec<C>,
+    goal: Option<usize>,
+}
+
+/// A* until a goal is reached, or until every reachable node is settled.
+fn explore<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Explored<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut heap = BinaryHeap::from([Entry {
+        estimate: heuristic(&start),
+        cost: C::default(),
+        index: 0,
+    }]);
+    let mut arena = Arena::new(start);
+    let mut costs = vec![C::default()];
+
+    while let Some(Entry { cost, index, .. }) = heap.pop() {
+        // a cheaper way to this node was found after this entry was pushed.
+        if cost > costs[index] {
+            continue;
+        }
+
+        if is_goal(&arena.nodes[index]) {
+            return Explored {
+                arena,
+                costs,
+                goal: Some(index),
+            };
+        }
+
+        for (next, step) in successors(&arena.nodes[index]) {
+            let next_cost = cost + step;
+
+            let next_index = match arena.get(&next) {
+                Some(next_index) if next_cost >= costs[next_index] => continue,
+                Some(next_index) => {
+                    costs[next_index] = next_cost;
+                    arena.parents[next_index] = index;
+                    next_index
+                }
+                None => {
+                    costs.push(next_cost);
+                    arena
+                        .insert(next, index)
+                        .expect("node was not discovered yet")
+                }
+            };
+
+            heap.push(Entry {
+                estimate: next_cost + heuristic(&arena.nodes[next_index]),
+                cost: next_cost,
+                index: next_index,
+            });
+        }
+    }
+
+    Explored {
+        arena,
+        costs,
+        goal: None,
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{astar, bfs, bfs_distances, dijkstra, dijkstra_distances, flood_fill};
+    use crate::{
+        geometry::Point2,
+        grid::{Grid, Position},
+    };
+
+    const MAZE: &str = "\
+S.#.....
+.##.###.
+....#...
+.####.#.
+......#E";
+
+    fn maze() -> Grid<char> {
+        MAZE.parse().unwrap()
+    }
+
+    fn open_neighbors(grid: &Grid<char>, position: Position) -> Vec<Position> {
+        grid.neighbors_4(position)
+            .filter(|next| grid[*next] != '#')
+            .collect()
+    }
+
+    #[test]
+    fn finds_shortest_paths() {
+        let grid = maze();
+        let goal = grid.find(|c| *c == 'E').unwrap();
+
+        let path = bfs((0, 0), |p| open_neighbors(&grid, *p), |p| *p == goal).unwrap();
+
+        assert_eq!(path.cost, 15);
+        assert_eq!(path.nodes.len(), 16);
+        assert_eq!(path.nodes.first(), Some(&(0, 0)));
+        assert_eq!(path.nodes.last(), Some(&goal));
+        assert!(path
+            .nodes
+            .windows(2)
+            .all(|w| grid.neighbors_4(w[0]).any(|n| n == w[1])));
+
+        assert_eq!(
+            bfs((0, 0), |p| open_neighbors(&grid, *p), |p| *p == (0, 3)).map(|p| p.cost),
+            Some(7)
+        );
+        assert_eq!(bfs(0, |_| vec![], |n| *n == 1), None);
+    }
+
+    #[test]
+    fn measures_distances() {
+        let grid = maze();
+        let distances = bfs_distances((0, 0), |p| open_neighbors(&grid, *p));
+
+        assert_eq!(distances[&(0, 0)], 0);
+        assert_eq!(distances[&(4, 7)], 15);
+        assert!(!distances.contains_key(&(0, 2)));
+    }
+
+    #[test]
+    fn finds_cheapest_paths() {
+        // going through b is one edge longer, but cheaper.
+        let edges = |node: &char| match node {
+            'a' => vec![('b', 1), ('c', 5)],
+            'b' => vec![('d', 1)],
+            'd' => vec![('c', 1)],
+            _ => vec![],
+        };
+
+        let path = dijkstra('a', edges, |n| *n == 'c').unwrap();
+        assert_eq!(path.nodes, vec!['a', 'b', 'd', 'c']);
+        assert_eq!(path.cost, 3);
+
+        let distances = dijkstra_distances('a', edges);
+        assert_eq!(distances.len(), 4);
+        assert_eq!(distances[&'c'], 3);
+    }
+
+    #[test]
+    fn finds_cheapest_paths_with_heuristics() {
+        let grid = maze();
+        let goal = Point2::new(7_usize, 4);
+
+        let path = astar(
+            Point2::new(0, 0),
+            |p| {
+                p.neighbors_4()
+                    .filter(|next| grid.get(*next).is_some_and(|c| *c != '#'))
+                    .map(|next| (next, 1))
+                    .collect::<Vec<_>>()
+            },
+            |p| p.manhattan(goal),
+            |p| *p == goal,
+        )
+        .unwrap();
+
+        assert_eq!(path.cost, 15);
+        assert_eq!(path.nodes.last(), Some(&goal));
+    }
+
+    #[test]
+    fn fills_regions() {
+        let grid = maze();
+        let walls = flood_fill([(0, 2)], |p| {
+            grid.neighbors_4(*p)
+                .filter(|next| grid[*next] == '#')
+                .collect::<Vec<_>>()
+        });
+
+        assert_eq!(walls.len(), 3);
+        assert!(walls.contains(&(1, 1)));
+        assert_eq!(
+            flood_fill([(0, 0)], |p| open_neighbors(&grid, *p)).len(),
+            bfs_distances((0, 0), |p| open_neighbors(&grid, *p)).len()
+        );
+    }
+}
+
+/* -------------------------------------------------------------------------- */
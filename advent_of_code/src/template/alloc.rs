@@ -0,0 +1,144 @@
+/// A global allocator that counts allocations, so the memory usage of each part can be reported.
+/// Registered for any binary that links this library with the `count-allocs` feature, unless `dhat-heap` is enabled.
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+use crate::template::stats::MemoryStats;
+
+#[global_allocator]
+static ALLOC: CountingAlloc = CountingAlloc;
+
+/// Allocations are counted per thread, so parts running at the same time don't count each other's.
+#[derive(Clone, Copy)]
+struct Counts {
+    current: u64,
+    peak: u64,
+    total: u64,
+    count: u64,
+}
+
+thread_local! {
+    static COUNTS: Cell<Counts> = const {
+        Cell::new(Counts {
+            current: 0,
+            peak: 0,
+            total: 0,
+            count: 0,
+        })
+    };
+}
+
+/// Forwards to the system allocator while keeping count.
+pub struct CountingAlloc;
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record_dealloc(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            record_dealloc(layout.size());
+            record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Updates the counts of the current thread. Allocations made while the thread shuts down are not counted.
+fn update(func: impl FnOnce(&mut Counts)) {
+    let _ = COUNTS.try_with(|counts| {
+        let mut updated = counts.get();
+        func(&mut updated);
+        counts.set(updated);
+    });
+}
+
+fn record_alloc(size: usize) {
+    let size = size as u64;
+    update(|counts| {
+        counts.count += 1;
+        counts.total += size;
+        counts.current += size;
+        counts.peak = counts.peak.max(counts.current);
+    });
+}
+
+/// Memory allocated by another thread may be freed here, so the current count can't go below zero.
+fn record_dealloc(size: usize) {
+    update(|counts| counts.current = counts.current.saturating_sub(size as u64));
+}
+
+/// Runs a function and returns the memory it allocated on the current thread.
+/// Allocations of threads spawned by the function are not included.
+pub fn measure<T>(func: impl FnOnce() -> T) -> (T, MemoryStats) {
+    let start = COUNTS.get();
+    update(|counts| counts.peak = counts.current);
+
+    let result = func();
+
+    let end = COUNTS.get();
+    let memory = MemoryStats {
+        peak_bytes: end.peak.saturating_sub(start.current),
+        total_bytes: end.total - start.total,
+        allocations: end.count - start.count,
+    };
+
+    (result, memory)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{hint::black_box, thread};
+
+    use super::measure;
+
+    #[test]
+    fn counts_allocations() {
+        let (_, memory) = measure(|| {
+            let first = black_box(vec![0_u8; 1000]);
+            drop(first);
+            black_box(vec![0_u8; 600])
+        });
+
+        assert!(memory.allocations >= 2);
+        assert!(memory.total_bytes >= 1600);
+        assert!(memory.peak_bytes >= 1000);
+    }
+
+    #[test]
+    fn ignores_allocations_of_other_threads() {
+        let (_, memory) = measure(|| {
+            thread::spawn(|| black_box(vec![0_u8; 100_000]))
+                .join()
+                .unwrap()
+        });
+
+        assert!(memory.total_bytes < 100_000);
+        assert!(memory.peak_bytes < 100_000);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,557 @@
+/// Known-good answers, used to verify the results of solutions.
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs,
+    io::{self, ErrorKind},
+    path::PathBuf,
+    str::FromStr,
+};
+use tinyjson::JsonValue;
+
+use crate::template::{year_dir, Day, Year};
+
+/// Answers are stored alongside the data files of each year.
+fn get_answers_path(year: Year) -> PathBuf {
+    year_dir(year).join("data").join("answers.json")
+}
+
+/// Represents the accepted answers for a single day, and the answers that were rejected.
+#[derive(Clone, Debug)]
+pub struct Answer {
+    pub day: Day,
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+    pub wrong: Vec<WrongGuess>,
+}
+
+/// An answer that was rejected by the server.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WrongGuess {
+    pub part: u8,
+    pub answer: String,
+    pub hint: Option<Hint>,
+}
+
+/// The hint the server gives for some wrong answers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+/// Represents the accepted answers for a set of days.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Answers {
+    pub data: Vec<Answer>,
+}
+
+/// The outcome of comparing a result against the accepted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    Unknown,
+}
+
+impl Answers {
+    /// Rehydrate answers from a JSON file. If not present, returns empty answers.
+    /// Fails if the file exists but can't be read, so it does not get overwritten with empty answers.
+    pub fn read_from_file(year: Year) -> Result<Self, String> {
+        match fs::read_to_string(get_answers_path(year)) {
+            Ok(s) => Answers::try_from(s),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    /// Dehydrate answers to a JSON file.
+    pub fn store_file(&self, year: Year) -> Result<(), io::Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(get_answers_path(year))?;
+        json.format_to(&mut file)
+    }
+
+    /// Returns the accepted answer for a part of a day, if known.
+    pub fn get(&self, day: Day, part: u8) -> Option<&str> {
+        let answer = self.data.iter().find(|a| a.day == day)?;
+
+        match part {
+            1 => answer.part_1.as_deref(),
+            2 => answer.part_2.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Compares a result against the accepted answer. A missing result is wrong if the answer is known.
+    pub fn verify(&self, day: Day, part: u8, result: Option<&str>) -> Verdict {
+        match self.get(day, part) {
+            None => Verdict::Unknown,
+            Some(answer) if result == Some(answer) => Verdict::Correct,
+            Some(_) => Verdict::Wrong,
+        }
+    }
+
+    /// Checks whether an answer is worth submitting, returns the reason if it is not.
+    pub fn check_submission(&self, day: Day, part: u8, answer: &str) -> Result<(), String> {
+        if let Some(accepted) = self.get(day, part) {
+            return Err(format!(
+                "part {part} has already been solved with answer \"{accepted}\"."
+            ));
+        }
+
+        let Some(entry) = self.data.iter().find(|a| a.day == day) else {
+            return Ok(());
+        };
+
+        for guess in entry.wrong.iter().filter(|guess| guess.part == part) {
+            if guess.answer == answer {
+                return Err(format!("\"{answer}\" has already been rejected."));
+            }
+
+            let (Ok(answer), Ok(guessed)) = (answer.parse::<i128>(), guess.answer.parse::<i128>())
+            else {
+                continue;
+            };
+
+            match guess.hint {
+                Some(Hint::TooHigh) if answer >= guessed => {
+                    return Err(format!("\"{guessed}\" was already too high."));
+                }
+                Some(Hint::TooLow) if answer <= guessed => {
+                    return Err(format!("\"{guessed}\" was already too low."));
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Stores the accepted answer for a part of a day.
+    pub fn accept(&mut self, day: Day, part: u8, answer: &str) {
+        let entry = self.entry(day);
+
+        match part {
+            1 => entry.part_1 = Some(answer.into()),
+            2 => entry.part_2 = Some(answer.into()),
+            _ => {}
+        }
+    }
+
+    /// Remembers an answer that was rejected.
+    pub fn reject(&mut self, day: Day, guess: WrongGuess) {
+        let entry = self.entry(day);
+
+        if !entry.wrong.contains(&guess) {
+            entry.wrong.push(guess);
+        }
+    }
+
+    fn entry(&mut self, day: Day) -> &mut Answer {
+        let index = match self.data.binary_search_by_key(&day, |a| a.day) {
+            Ok(index) => index,
+            Err(index) => {
+                self.data.insert(
+                    index,
+                    Answer {
+                        day,
+                        part_1: None,
+                        part_2: None,
+                        wrong: vec![],
+                    },
+                );
+                index
+            }
+        };
+
+        &mut self.data[index]
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "✔"),
+            Verdict::Wrong => write!(f, "✖"),
+            Verdict::Unknown => write!(f, "?"),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl Verdict {
+    /// The name of the verdict in JSON documents.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::Wrong => "wrong",
+            Verdict::Unknown => "unknown",
+        }
+    }
+}
+
+impl Hint {
+    /// The name of the hint in JSON documents.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Hint::TooHigh => "too_high",
+            Hint::TooLow => "too_low",
+        }
+    }
+}
+
+impl FromStr for Hint {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "too_high" => Ok(Hint::TooHigh),
+            "too_low" => Ok(Hint::TooLow),
+            _ => Err(format!("unknown hint `{s}`.")),
+        }
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "wrong" => Ok(Verdict::Wrong),
+            "unknown" => Ok(Verdict::Unknown),
+            _ => Err(format!("unknown verdict `{s}`.")),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Answers> for JsonValue {
+    fn from(value: Answers) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Answers {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        let mut data: Vec<Answer> = json_data
+            .iter()
+            .map(Answer::try_from)
+            .collect::<Result<_, _>>()?;
+
+        // answers are looked up by day with a binary search, and the file may have been edited by hand.
+        data.sort_unstable_by_key(|a| a.day);
+
+        Ok(Answers { data })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Answer> for JsonValue {
+    fn from(value: &Answer) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+
+        map.insert(
+            "part_1".into(),
+            match &value.part_1 {
+                Some(x) => JsonValue::String(x.clone()),
+                None => JsonValue::Null,
+            },
+        );
+
+        map.insert(
+            "part_2".into(),
+            match &value.part_2 {
+                Some(x) => JsonValue::String(x.clone()),
+                None => JsonValue::Null,
+            },
+        );
+
+        map.insert(
+            "wrong".into(),
+            JsonValue::Array(value.wrong.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Answer {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected answer to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected answer.day to be a Day struct.")?;
+
+        let part_1 = json
+            .get("part_1")
+            .map_or(Some(None), |v| {
+                if v.is_null() {
+                    Some(None)
+                } else {
+                    v.get::<String>().map(Some)
+                }
+            })
+            .ok_or("Expected answer.part_1 to be null or string.")?;
+
+        let part_2 = json
+            .get("part_2")
+            .map_or(Some(None), |v| {
+                if v.is_null() {
+                    Some(None)
+                } else {
+                    v.get::<String>().map(Some)
+                }
+            })
+            .ok_or("Expected answer.part_2 to be null or string.")?;
+
+        // NOTE: answers files that predate tracking wrong guesses do not have this key.
+        let wrong = match json.get("wrong") {
+            None => vec![],
+            Some(v) => v
+                .get::<Vec<JsonValue>>()
+                .ok_or("Expected answer.wrong to be an array.")?
+                .iter()
+                .map(WrongGuess::try_from)
+                .collect::<Result<_, _>>()?,
+        };
+
+        Ok(Answer {
+            day,
+            part_1: part_1.cloned(),
+            part_2: part_2.cloned(),
+            wrong,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&WrongGuess> for JsonValue {
+    fn from(value: &WrongGuess) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+        map.insert(
+            "hint".into(),
+            match value.hint {
+                Some(hint) => JsonValue::String(hint.as_str().into()),
+                None => JsonValue::Null,
+            },
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for WrongGuess {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected wrong guess to be a JSON object.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .ok_or("Expected wrong.part to be a number.")?;
+
+        let answer = json
+            .get("answer")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected wrong.answer to be a string.")?;
+
+        let hint = json
+            .get("hint")
+            .map(|v| {
+                if v.is_null() {
+                    Ok(None)
+                } else {
+                    v.get::<String>()
+                        .ok_or_else(|| "Expected wrong.hint to be null or string.".to_string())
+                        .and_then(|hint| hint.parse().map(Some))
+                }
+            })
+            .ok_or("Expected wrong.hint to be null or string.")??;
+
+        Ok(WrongGuess {
+            part: *part as u8,
+            answer: answer.clone(),
+            hint,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use crate::day;
+
+    use super::{Answers, Hint, Verdict, WrongGuess};
+    use tinyjson::JsonValue;
+
+    fn get_mock_answers() -> Answers {
+        let json = r#"{ "data": [{ "day": "01", "part_1": "1292", "part_2": "line one\nline two" }, { "day": "02", "part_1": "7" }] }"#;
+        Answers::try_from(json.to_string()).unwrap()
+    }
+
+    fn get_mock_guesses() -> Answers {
+        let mut answers = Answers::default();
+        answers.reject(
+            day!(3),
+            WrongGuess {
+                part: 1,
+                answer: "100".into(),
+                hint: Some(Hint::TooHigh),
+            },
+        );
+        answers.reject(
+            day!(3),
+            WrongGuess {
+                part: 1,
+                answer: "20".into(),
+                hint: Some(Hint::TooLow),
+            },
+        );
+        answers.reject(
+            day!(3),
+            WrongGuess {
+                part: 2,
+                answer: "ABC".into(),
+                hint: None,
+            },
+        );
+        answers
+    }
+
+    #[test]
+    fn verifies_correct_answers() {
+        let answers = get_mock_answers();
+        assert_eq!(answers.verify(day!(1), 1, Some("1292")), Verdict::Correct);
+        assert_eq!(
+            answers.verify(day!(1), 2, Some("line one\nline two")),
+            Verdict::Correct
+        );
+    }
+
+    #[test]
+    fn verifies_wrong_answers() {
+        let answers = get_mock_answers();
+        assert_eq!(answers.verify(day!(1), 1, Some("1293")), Verdict::Wrong);
+        assert_eq!(answers.verify(day!(2), 1, None), Verdict::Wrong);
+    }
+
+    #[test]
+    fn verifies_unknown_answers() {
+        let answers = get_mock_answers();
+        assert_eq!(answers.verify(day!(2), 2, Some("7")), Verdict::Unknown);
+        assert_eq!(answers.verify(day!(3), 1, Some("7")), Verdict::Unknown);
+        assert_eq!(answers.verify(day!(3), 1, None), Verdict::Unknown);
+    }
+
+    #[test]
+    fn refuses_known_answers() {
+        let answers = get_mock_answers();
+        assert!(answers.check_submission(day!(1), 1, "1292").is_err());
+        assert!(answers.check_submission(day!(1), 1, "1").is_err());
+        assert!(answers.check_submission(day!(2), 2, "1").is_ok());
+    }
+
+    #[test]
+    fn refuses_wrong_guesses() {
+        let answers = get_mock_guesses();
+        assert!(answers.check_submission(day!(3), 2, "ABC").is_err());
+        assert!(answers.check_submission(day!(3), 2, "ABD").is_ok());
+        assert!(answers.check_submission(day!(3), 1, "100").is_err());
+    }
+
+    #[test]
+    fn refuses_guesses_out_of_bounds() {
+        let answers = get_mock_guesses();
+        assert!(answers.check_submission(day!(3), 1, "101").is_err());
+        assert!(answers.check_submission(day!(3), 1, "19").is_err());
+        assert!(answers.check_submission(day!(3), 1, "20").is_err());
+        assert!(answers.check_submission(day!(3), 1, "50").is_ok());
+        assert!(answers.check_submission(day!(4), 1, "101").is_ok());
+    }
+
+    #[test]
+    fn accepts_answers() {
+        let mut answers = get_mock_guesses();
+        answers.accept(day!(3), 1, "50");
+        answers.accept(day!(1), 2, "2");
+        assert_eq!(answers.verify(day!(3), 1, Some("50")), Verdict::Correct);
+        assert_eq!(answers.verify(day!(1), 2, Some("2")), Verdict::Correct);
+        assert_eq!(answers.data[0].day, day!(1));
+        assert_eq!(answers.data[1].day, day!(3));
+    }
+
+    #[test]
+    fn sorts_answers_by_day() {
+        let json =
+            r#"{ "data": [{ "day": "05", "part_1": "5" }, { "day": "02", "part_1": "2" }] }"#;
+        let mut answers = Answers::try_from(json.to_string()).unwrap();
+        answers.accept(day!(5), 2, "55");
+        answers.accept(day!(3), 1, "3");
+
+        assert_eq!(answers.data.len(), 3);
+        assert_eq!(answers.data[0].day, day!(2));
+        assert_eq!(answers.data[1].day, day!(3));
+        assert_eq!(answers.verify(day!(5), 1, Some("5")), Verdict::Correct);
+        assert_eq!(answers.verify(day!(5), 2, Some("55")), Verdict::Correct);
+    }
+
+    #[test]
+    fn serializes_answers() {
+        let answers = get_mock_guesses();
+        let json = JsonValue::from(answers.clone()).stringify().unwrap();
+        let deserialized = Answers::try_from(json).unwrap();
+        assert_eq!(deserialized.data[0].wrong, answers.data[0].wrong);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_malformed_answers() {
+        let json = r#"{ "data": [{ "day": "01", "part_1": 1292 }] }"#;
+        Answers::try_from(json.to_string()).unwrap();
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,417 @@
+/// Wrapper module around the "aoc-cli" command-line.
+use std::{
+    ffi::OsStr,
+    fmt::Display,
+    process::{Command, Output, Stdio},
+    time::Duration,
+};
+
+use crate::template::answers::Hint;
+use crate::template::Puzzle;
+
+#[derive(Debug)]
+pub enum AocCommandError {
+    CommandNotFound,
+    CommandNotCallable,
+    BadExitStatus(Output),
+}
+
+impl Display for AocCommandError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocCommandError::CommandNotFound => write!(f, "aoc-cli is not present in environment."),
+            AocCommandError::CommandNotCallable => write!(f, "aoc-cli could not be called."),
+            AocCommandError::BadExitStatus(_) => {
+                write!(f, "aoc-cli exited with a non-zero status.")
+            }
+        }
+    }
+}
+
+/// The response of the server to a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubmissionOutcome {
+    Correct,
+    Wrong {
+        hint: Option<Hint>,
+        wait: Option<Duration>,
+    },
+    /// An answer was submitted too recently.
+    TooSoon {
+        wait: Option<Duration>,
+    },
+    /// The part has already been solved, or is not unlocked yet.
+    WrongLevel,
+    Unknown,
+}
+
+pub fn check() -> Result<(), AocCommandError> {
+    Command::new("aoc")
+        .arg("-V")
+        .output()
+        .map_err(|_| AocCommandError::CommandNotFound)?;
+    Ok(())
+}
+
+pub fn read(puzzle: Puzzle) -> Result<Output, AocCommandError> {
+    let puzzle_path = get_puzzle_path(puzzle);
+
+    let args = build_args(
+        "read",
+        &[
+            "--description-only".into(),
+            "--puzzle-file".into(),
+            puzzle_path,
+        ],
+        puzzle,
+    );
+
+    call_aoc_cli(&args)
+}
+
+/// Downloads the puzzle description, and the input unless `include_input` is `false`.
+pub fn download(puzzle: Puzzle, include_input: bool) -> Result<Output, AocCommandError> {
+    let input_path = get_input_path(puzzle);
+    let puzzle_path = get_puzzle_path(puzzle);
+
+    let mut args = vec![
+        "--overwrite".into(),
+        "--input-file".into(),
+        input_path.to_string(),
+        "--puzzle-file".into(),
+        puzzle_path.to_string(),
+    ];
+    if !include_input {
+        args.push("--puzzle-only".into());
+    }
+
+    let output = call_aoc_cli(&build_args("download", &args, puzzle))?;
+    println!("---");
+    if include_input {
+        println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+    }
+    println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+    Ok(output)
+}
+
+pub fn submit(
+    puzzle: Puzzle,
+    part: u8,
+    result: &str,
+) -> Result<SubmissionOutcome, AocCommandError> {
+    submit_with("aoc", puzzle, part, result)
+}
+
+/// Like [`submit`], but calls `program` instead of the aoc-cli found on `PATH`.
+pub(crate) fn submit_with(
+    program: impl AsRef<OsStr>,
+    puzzle: Puzzle,
+    part: u8,
+    result: &str,
+) -> Result<SubmissionOutcome, AocCommandError> {
+    // workaround: the argument order is inverted for submit.
+    let mut args = build_args("submit", &[], puzzle);
+    args.push(part.to_string());
+    args.push(result.to_string());
+
+    let output = call_aoc_cli_captured(program, &args)?;
+    let response = String::from_utf8_lossy(&output.stdout);
+    print!("{response}");
+    Ok(parse_submission(&response))
+}
+
+/// Interprets the response to a submission as printed by aoc-cli.
+pub fn parse_submission(response: &str) -> SubmissionOutcome {
+    let response = response.to_lowercase();
+
+    if response.contains("that's the right answer") {
+        SubmissionOutcome::Correct
+    } else if response.contains("that's not the right answer") {
+        let hint = if response.contains("too high") {
+            Some(Hint::TooHigh)
+        } else if response.contains("too low") {
+            Some(Hint::TooLow)
+        } else {
+            None
+        };
+
+        SubmissionOutcome::Wrong {
+            hint,
+            wait: parse_wait(&response),
+        }
+    } else if response.contains("you gave an answer too recently") {
+        SubmissionOutcome::TooSoon {
+            wait: parse_wait(&response),
+        }
+    } else if response.contains("solving the right level") {
+        SubmissionOutcome::WrongLevel
+    } else {
+        SubmissionOutcome::Unknown
+    }
+}
+
+fn get_input_path(puzzle: Puzzle) -> String {
+    puzzle.input_path().display().to_string()
+}
+
+fn get_puzzle_path(puzzle: Puzzle) -> String {
+    puzzle.description_path().display().to_string()
+}
+
+fn build_args(command: &str, args: &[String], puzzle: Puzzle) -> Vec<String> {
+    let mut cmd_args = args.to_vec();
+
+    cmd_args.append(&mut vec![
+        "--year".into(),
+        puzzle.year.to_string(),
+        "--day".into(),
+        puzzle.day.to_string(),
+        command.into(),
+    ]);
+
+    cmd_args
+}
+
+fn call_aoc_cli(args: &[String]) -> Result<Output, AocCommandError> {
+    // println!("Calling >aoc with: {}", args.join(" "));
+    let output = Command::new("aoc")
+        .args(args)
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|_| AocCommandError::CommandNotCallable)?;
+
+    if output.status.success() {
+        Ok(output)
+    } else {
+        Err(AocCommandError::BadExitStatus(output))
+    }
+}
+
+/// Extracts the wait from responses like "you have 1m 4s left to wait" or "please wait 5 minutes before trying again".
+fn parse_wait(response: &str) -> Option<Duration> {
+    if let Some(end) = response.find(" left to wait") {
+        let start = response[..end].rfind("you have ")? + "you have ".len();
+
+        return response[start..end]
+            .split_whitespace()
+            .map(|token| {
+                if let Some(hours) = token.strip_suffix('h') {
+                    Some(hours.parse::<u64>().ok()? * 3600)
+                } else if let Some(minutes) = token.strip_suffix('m') {
+                    Some(minutes.parse::<u64>().ok()? * 60)
+                } else {
+                    token.strip_suffix('s')?.parse::<u64>().ok()
+                }
+            })
+            .sum::<Option<u64>>()
+            .map(Duration::from_secs);
+    }
+
+    let start = response.find("please wait ")? + "please wait ".len();
+    let mut words = response[start..].split_whitespace();
+
+    let value: u64 = match words.next()? {
+        "one" => 1,
+        value => value.parse().ok()?,
+    };
+
+    match words.next()? {
+        unit if unit.starts_with("minute") => Some(Duration::from_secs(value * 60)),
+        unit if unit.starts_with("second") => Some(Duration::from_secs(value)),
+        _ => None,
+    }
+}
+
+/// Like [`call_aoc_cli`], but captures stdout so that the response can be inspected.
+fn call_aoc_cli_captured(
+    program: impl AsRef<OsStr>,
+    args: &[String],
+) -> Result<Output, AocCommandError> {
+    let output = Command::new(program)
+        .args(args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|_| AocCommandError::CommandNotCallable)?;
+
+    if output.status.success() {
+        Ok(output)
+    } else {
+        print!("{}", String::from_utf8_lossy(&output.stdout));
+        Err(AocCommandError::BadExitStatus(output))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{parse_submission, SubmissionOutcome};
+    use crate::template::answers::Hint;
+
+    #[test]
+    fn parses_correct_answers() {
+        let response = "That's the right answer! You are one gold star closer to finding the sleigh keys. [Return to Day 1]";
+        assert_eq!(parse_submission(response), SubmissionOutcome::Correct);
+    }
+
+    #[test]
+    fn parses_wrong_answers() {
+        let response = "That's not the right answer; your answer is too high. If you're stuck, make sure you're using the full input data. Please wait one minute before trying again. [Return to Day 1]";
+        assert_eq!(
+            parse_submission(response),
+            SubmissionOutcome::Wrong {
+                hint: Some(Hint::TooHigh),
+                wait: Some(Duration::from_secs(60))
+            }
+        );
+
+        let response = "That's not the right answer; your answer is too low. Please wait 5 minutes before trying again.";
+        assert_eq!(
+            parse_submission(response),
+            SubmissionOutcome::Wrong {
+                hint: Some(Hint::TooLow),
+                wait: Some(Duration::from_secs(300))
+            }
+        );
+
+        let response = "That's not the right answer. If you're stuck, make sure you're using the full input data; there are also some general tips on the about page.";
+        assert_eq!(
+            parse_submission(response),
+            SubmissionOutcome::Wrong {
+                hint: None,
+                wait: None
+            }
+        );
+    }
+
+    #[test]
+    fn parses_waits() {
+        let response = "You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 1m 4s left to wait.";
+        assert_eq!(
+            parse_submission(response),
+            SubmissionOutcome::TooSoon {
+                wait: Some(Duration::from_secs(64))
+            }
+        );
+
+        let response = "You gave an answer too recently. You have ages left to wait.";
+        assert_eq!(
+            parse_submission(response),
+            SubmissionOutcome::TooSoon { wait: None }
+        );
+    }
+
+    #[test]
+    fn parses_other_responses() {
+        let response = "You don't seem to be solving the right level. Did you already complete it?";
+        assert_eq!(parse_submission(response), SubmissionOutcome::WrongLevel);
+
+        assert_eq!(parse_submission(""), SubmissionOutcome::Unknown);
+    }
+
+    /// Submits to a stub of aoc-cli that answers `111` with a cooldown and anything else as wrong,
+    /// and records the answers submitted to it.
+    #[cfg(unix)]
+    #[test]
+    fn records_cooldowns_of_submissions() {
+        use std::{env, fs, os::unix::fs::PermissionsExt, process};
+
+        use super::submit_with;
+        use crate::template::answers::Answers;
+        use crate::template::cooldowns::{self, Cooldowns};
+        use crate::template::runner::submit_and_record;
+        use crate::template::Puzzle;
+
+        let dir = env::temp_dir().join(format!("aoc-cli-stub-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let script = dir.join("aoc");
+        let log = dir.join("submitted.txt");
+        fs::write(
+            &script,
+            format!(
+                r#"#!/bin/sh
+for answer; do :; done
+echo "$answer" >> "{}"
+case "$answer" in
+    111) echo "You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 30s left to wait." ;;
+    *) echo "That's not the right answer; your answer is too high. Please wait one minute before trying again." ;;
+esac
+"#,
+                log.display()
+            ),
+        )
+        .unwrap();
+        fs::set_permissions(&script, fs::Permissions::from_mode(0o755)).unwrap();
+
+        let stub = |puzzle, part, answer: &str| submit_with(&script, puzzle, part, answer).ok();
+
+        let puzzle = Puzzle::new(crate::year!(2023), crate::day!(1));
+        let submitted = || fs::read_to_string(&log).unwrap_or_default();
+
+        // a response that asks to wait records the cooldown, and the next submission is refused.
+        let mut answers = Answers::default();
+        let mut cooldowns = Cooldowns::default();
+        assert!(submit_and_record(
+            &mut answers,
+            &mut cooldowns,
+            puzzle,
+            1,
+            "111".into(),
+            false,
+            stub
+        ));
+        let remaining = cooldowns
+            .remaining(puzzle.day, 1, cooldowns::now())
+            .unwrap();
+        assert!(remaining > Duration::from_secs(25) && remaining <= Duration::from_secs(30));
+        assert!(!submit_and_record(
+            &mut answers,
+            &mut cooldowns,
+            puzzle,
+            1,
+            "222".into(),
+            false,
+            stub
+        ));
+        assert_eq!(submitted(), "111\n");
+
+        // a wrong answer records the guess along with the cooldown.
+        assert!(submit_and_record(
+            &mut answers,
+            &mut cooldowns,
+            puzzle,
+            2,
+            "222".into(),
+            false,
+            stub
+        ));
+        assert_eq!(
+            answers.check_submission(puzzle.day, 2, "222"),
+            Err("\"222\" has already been rejected.".into())
+        );
+        assert!(
+            cooldowns
+                .remaining(puzzle.day, 2, cooldowns::now())
+                .unwrap()
+                > Duration::from_secs(55)
+        );
+        assert!(!submit_and_record(
+            &mut answers,
+            &mut cooldowns,
+            puzzle,
+            2,
+            "100".into(),
+            false,
+            stub
+        ));
+        assert_eq!(submitted(), "111\n222\n");
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,466 @@
+/// Built-in client for adventofcode.com, an alternative to the "aoc-cli" command-line.
+///
+/// Like aoc-cli, the session cookie is read from the `ADVENT_OF_CODE_SESSION` environment variable
+/// or the `~/.adventofcode.session` file. `AOC_BASE_URL` points the client at a different server.
+use std::{env, fmt::Display, fs, io, path::PathBuf};
+
+use crate::template::aoc_cli::{parse_submission, SubmissionOutcome};
+use crate::template::Puzzle;
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const SESSION_ENV_VAR: &str = "ADVENT_OF_CODE_SESSION";
+const SESSION_FILE: &str = ".adventofcode.session";
+const USER_AGENT: &str = "github.com/flimblewitz/advent_of_code";
+
+#[derive(Debug)]
+pub enum AocClientError {
+    SessionNotFound,
+    RequestFailed(String),
+    BadStatus(u16),
+    UnexpectedResponse,
+    IO(io::Error),
+}
+
+impl From<io::Error> for AocClientError {
+    fn from(e: io::Error) -> Self {
+        AocClientError::IO(e)
+    }
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::SessionNotFound => write!(
+                f,
+                "session cookie not found. Set {SESSION_ENV_VAR} or store it in ~/{SESSION_FILE}."
+            ),
+            AocClientError::RequestFailed(e) => write!(f, "request failed: {e}"),
+            AocClientError::BadStatus(status) => {
+                write!(f, "server responded with status {status}.")
+            }
+            AocClientError::UnexpectedResponse => {
+                write!(f, "could not find the puzzle in the response.")
+            }
+            AocClientError::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+pub struct AocClient {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl AocClient {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').into(),
+            session: session.trim().into(),
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    /// Creates a client with the session cookie of the environment, see the module documentation.
+    pub fn from_env() -> Result<Self, AocClientError> {
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+
+        let session = match env::var(SESSION_ENV_VAR) {
+            Ok(session) => session,
+            Err(_) => get_session_path()
+                .and_then(|path| fs::read_to_string(path).ok())
+                .ok_or(AocClientError::SessionNotFound)?,
+        };
+
+        if session.trim().is_empty() {
+            return Err(AocClientError::SessionNotFound);
+        }
+
+        Ok(Self::new(&base_url, &session))
+    }
+
+    /// Fetches the puzzle input.
+    pub fn input(&self, puzzle: Puzzle) -> Result<String, AocClientError> {
+        self.get(&format!("{}/input", day_path(puzzle)))
+    }
+
+    /// Fetches the puzzle description, converted to Markdown.
+    /// Once part one has been solved, this includes the description of part two.
+    pub fn description(&self, puzzle: Puzzle) -> Result<String, AocClientError> {
+        let html = self.get(&day_path(puzzle))?;
+        let articles = find_articles(&html);
+
+        if articles.is_empty() {
+            return Err(AocClientError::UnexpectedResponse);
+        }
+
+        Ok(articles
+            .iter()
+            .map(|article| html_to_markdown(article))
+            .collect::<Vec<_>>()
+            .join("\n\n"))
+    }
+
+    /// Submits an answer for a part of the puzzle.
+    pub fn submit(
+        &self,
+        puzzle: Puzzle,
+        part: u8,
+        answer: &str,
+    ) -> Result<(SubmissionOutcome, String), AocClientError> {
+        let url = format!("{}{}/answer", self.base_url, day_path(puzzle));
+
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)]);
+
+        let html = read_response(response)?;
+        let text = find_articles(&html).first().map_or_else(
+            || html_to_markdown(&html),
+            |article| html_to_markdown(article),
+        );
+
+        Ok((parse_submission(&text), text))
+    }
+
+    fn get(&self, path: &str) -> Result<String, AocClientError> {
+        let url = format!("{}{path}", self.base_url);
+
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+
+        read_response(response)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Mirrors [`aoc_cli::read`](crate::template::aoc_cli::read): stores and prints the puzzle description.
+pub fn read(puzzle: Puzzle) -> Result<(), AocClientError> {
+    let client = AocClient::from_env()?;
+    let description = client.description(puzzle)?;
+
+    fs::write(puzzle.description_path(), &description)?;
+    println!("{description}");
+    Ok(())
+}
+
+/// Mirrors [`aoc_cli::download`](crate::template::aoc_cli::download): stores the puzzle description,
+/// and the input unless `include_input` is `false`.
+pub fn download(puzzle: Puzzle, include_input: bool) -> Result<(), AocClientError> {
+    let client = AocClient::from_env()?;
+    let input_path = puzzle.input_path();
+    let puzzle_path = puzzle.description_path();
+
+    if include_input {
+        fs::write(&input_path, client.input(puzzle)?)?;
+    }
+    fs::write(&puzzle_path, client.description(puzzle)?)?;
+
+    println!("---");
+    if include_input {
+        println!(
+            "🎄 Successfully wrote input to \"{}\".",
+            input_path.display()
+        );
+    }
+    println!(
+        "🎄 Successfully wrote puzzle to \"{}\".",
+        puzzle_path.display()
+    );
+    Ok(())
+}
+
+/// Mirrors [`aoc_cli::submit`](crate::template::aoc_cli::submit): submits and prints the response.
+pub fn submit(puzzle: Puzzle, part: u8, result: &str) -> Result<SubmissionOutcome, AocClientError> {
+    let client = AocClient::from_env()?;
+    let (outcome, response) = client.submit(puzzle, part, result)?;
+    println!("{response}");
+    Ok(outcome)
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn get_session_path() -> Option<PathBuf> {
+    env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(|home| PathBuf::from(home).join(SESSION_FILE))
+}
+
+fn day_path(puzzle: Puzzle) -> String {
+    format!(
+        "/{}/day/{}",
+        puzzle.year.into_inner(),
+        puzzle.day.into_inner()
+    )
+}
+
+fn read_response(response: Result<ureq::Response, ureq::Error>) -> Result<String, AocClientError> {
+    match response {
+        Ok(response) => response
+            .into_string()
+            .map_err(|e| AocClientError::RequestFailed(e.to_string())),
+        Err(ureq::Error::Status(status, _)) => Err(AocClientError::BadStatus(status)),
+        Err(e) => Err(AocClientError::RequestFailed(e.to_string())),
+    }
+}
+
+/// Returns the contents of every `<article>` element.
+fn find_articles(html: &str) -> Vec<&str> {
+    let mut articles = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let Some(open_end) = rest[start..].find('>') else {
+            break;
+        };
+        let content_start = start + open_end + 1;
+        let Some(length) = rest[content_start..].find("</article>") else {
+            break;
+        };
+
+        articles.push(&rest[content_start..content_start + length]);
+        rest = &rest[content_start + length..];
+    }
+
+    articles
+}
+
+/// Converts the subset of HTML used by puzzle descriptions to Markdown.
+fn html_to_markdown(html: &str) -> String {
+    let mut markdown = String::new();
+    let mut link_targets: Vec<String> = vec![];
+    let mut in_pre = false;
+    let mut in_code = false;
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let Some(tag_start) = rest.find('<') else {
+            markdown.push_str(&decode_entities(rest));
+            break;
+        };
+
+        markdown.push_str(&decode_entities(&rest[..tag_start]));
+
+        let Some(tag_length) = rest[tag_start..].find('>') else {
+            break;
+        };
+        let tag = &rest[tag_start + 1..tag_start + tag_length];
+        rest = &rest[tag_start + tag_length + 1..];
+
+        let is_closing = tag.starts_with('/');
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default();
+
+        match (name, is_closing) {
+            ("h2", false) => markdown.push_str("## "),
+            ("h2" | "p" | "ul", true) => markdown.push_str("\n\n"),
+            ("pre", false) => {
+                in_pre = true;
+                markdown.push_str("```\n");
+            }
+            ("pre", true) => {
+                in_pre = false;
+                if !markdown.ends_with('\n') {
+                    markdown.push('\n');
+                }
+                markdown.push_str("```\n\n");
+            }
+            ("code", _) if !in_pre => {
+                in_code = !is_closing;
+                markdown.push('`');
+            }
+            ("em", _) if !in_pre && !in_code => markdown.push('*'),
+            ("li", false) => markdown.push_str("- "),
+            ("li", true) => markdown.push('\n'),
+            ("a", false) => {
+                link_targets.push(get_attribute(tag, "href").unwrap_or_default().into());
+                markdown.push('[');
+            }
+            ("a", true) => {
+                let target = link_targets.pop().unwrap_or_default();
+                markdown.push_str(&format!("]({target})"));
+            }
+            _ => {}
+        }
+    }
+
+    markdown.trim().to_string()
+}
+
+fn get_attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let start = tag.find(&format!("{name}=\""))? + name.len() + 2;
+    let length = tag[start..].find('"')?;
+    Some(&tag[start..start + length])
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::mpsc,
+        thread,
+    };
+
+    use super::{html_to_markdown, AocClient, AocClientError};
+    use crate::template::answers::Hint;
+    use crate::template::aoc_cli::SubmissionOutcome;
+    use crate::template::Puzzle;
+    use crate::{day, year};
+
+    /// A received request: the request line, the cookie header and the body.
+    type Request = (String, String, String);
+
+    /// Serves one canned response per request on a local port, reporting the requests it received.
+    fn serve(responses: Vec<(u16, &'static str)>) -> (String, mpsc::Receiver<Request>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+
+                let mut cookie = String::new();
+                let mut content_length = 0;
+
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    let header = header.trim_end();
+
+                    if header.is_empty() {
+                        break;
+                    }
+
+                    let (name, value) = header.split_once(": ").unwrap();
+                    match name.to_lowercase().as_str() {
+                        "cookie" => cookie = value.into(),
+                        "content-length" => content_length = value.parse().unwrap(),
+                        _ => {}
+                    }
+                }
+
+                let mut request_body = vec![0; content_length];
+                reader.read_exact(&mut request_body).unwrap();
+
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+
+                sender
+                    .send((
+                        request_line.trim_end().into(),
+                        cookie,
+                        String::from_utf8(request_body).unwrap(),
+                    ))
+                    .unwrap();
+            }
+        });
+
+        (base_url, receiver)
+    }
+
+    fn puzzle() -> Puzzle {
+        Puzzle::new(year!(2022), day!(5))
+    }
+
+    #[test]
+    fn downloads_inputs() {
+        let (base_url, requests) = serve(vec![(200, "1\n2\n3\n")]);
+        let client = AocClient::new(&base_url, "secret\n");
+
+        assert_eq!(client.input(puzzle()).unwrap(), "1\n2\n3\n");
+
+        let (request_line, cookie, _) = requests.recv().unwrap();
+        assert_eq!(request_line, "GET /2022/day/5/input HTTP/1.1");
+        assert_eq!(cookie, "session=secret");
+    }
+
+    #[test]
+    fn fetches_descriptions() {
+        let (base_url, requests) = serve(vec![(
+            200,
+            r#"<main><article class="day-desc"><h2>--- Day 5: Test ---</h2><p>Find the <em>top</em> crate &amp; <a href="/2022/about">more</a>:</p><pre><code>[D]
+1 2
+</code></pre><ul><li><code>move 1</code></li></ul></article></main>"#,
+        )]);
+        let client = AocClient::new(&base_url, "secret");
+
+        assert_eq!(
+            client.description(puzzle()).unwrap(),
+            "## --- Day 5: Test ---\n\nFind the *top* crate & [more](/2022/about):\n\n```\n[D]\n1 2\n```\n\n- `move 1`"
+        );
+        assert_eq!(requests.recv().unwrap().0, "GET /2022/day/5 HTTP/1.1");
+    }
+
+    #[test]
+    fn submits_answers() {
+        let (base_url, requests) = serve(vec![(
+            200,
+            "<main><article><p>That's not the right answer; your answer is too high. Please wait one minute before trying again.</p></article></main>",
+        )]);
+        let client = AocClient::new(&base_url, "secret");
+
+        let (outcome, _) = client.submit(puzzle(), 2, "CMZ").unwrap();
+        assert!(matches!(
+            outcome,
+            SubmissionOutcome::Wrong {
+                hint: Some(Hint::TooHigh),
+                wait: Some(_)
+            }
+        ));
+
+        let (request_line, _, body) = requests.recv().unwrap();
+        assert_eq!(request_line, "POST /2022/day/5/answer HTTP/1.1");
+        assert_eq!(body, "level=2&answer=CMZ");
+    }
+
+    #[test]
+    fn reports_bad_status() {
+        let (base_url, _requests) = serve(vec![(400, "Puzzle inputs differ by user.")]);
+        let client = AocClient::new(&base_url, "expired");
+
+        assert!(matches!(
+            client.input(puzzle()),
+            Err(AocClientError::BadStatus(400))
+        ));
+    }
+
+    #[test]
+    fn keeps_emphasis_in_code_plain() {
+        assert_eq!(
+            html_to_markdown("<p>Total: <code><em>42</em></code></p>"),
+            "Total: `42`"
+        );
+    }
+}
+
+/* -------------------------------------------------------------------------- */
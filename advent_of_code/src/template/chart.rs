@@ -0,0 +1,272 @@
+/// Self-contained HTML reports of benchmark timings, with an SVG bar chart per day and part, see `time --report`.
+use std::{fmt::Write, fs, io, path::Path, time::Duration};
+
+use crate::template::{runner::PARSE_PART, timings::Timings, Year};
+
+const WIDTH: f64 = 860.0;
+const LABEL_WIDTH: f64 = 70.0;
+const MARGIN: f64 = 20.0;
+const AXIS_HEIGHT: f64 = 30.0;
+const BAR_HEIGHT: f64 = 10.0;
+const BAR_GAP: f64 = 2.0;
+const DAY_GAP: f64 = 10.0;
+
+/// The steps of a day in the order they are drawn, with their label and color.
+const SERIES: [(u8, &str, &str); 3] = [
+    (PARSE_PART, "Parse", "#8c8c8c"),
+    (1, "Part 1", "#4e79a7"),
+    (2, "Part 2", "#f28e2b"),
+];
+
+const STYLE: &str = "body { font-family: sans-serif; margin: 2em; color: #222; }
+svg { font-size: 12px; }
+.grid { stroke: #ddd; }
+.legend span { display: inline-block; width: 12px; height: 12px; margin: 0 4px 0 12px; vertical-align: middle; }";
+
+/// Renders an HTML document with a bar chart of the median duration of every part, on a log scale.
+#[must_use]
+pub fn render(year: Year, timings: &Timings) -> String {
+    let mut html = String::new();
+
+    writeln!(html, "<!DOCTYPE html>").unwrap();
+    writeln!(html, "<html lang=\"en\">").unwrap();
+    writeln!(html, "<head>").unwrap();
+    writeln!(html, "<meta charset=\"utf-8\">").unwrap();
+    writeln!(html, "<title>Advent of Code {year} benchmarks</title>").unwrap();
+    writeln!(html, "<style>\n{STYLE}\n</style>").unwrap();
+    writeln!(html, "</head>").unwrap();
+    writeln!(html, "<body>").unwrap();
+    writeln!(html, "<h1>Advent of Code {year} benchmarks</h1>").unwrap();
+
+    match render_chart(timings) {
+        Some(svg) => {
+            writeln!(
+                html,
+                "<p>Total: {:.2}ms. Median duration of each part, on a log scale.</p>",
+                timings.total_millis()
+            )
+            .unwrap();
+            write!(html, "<p class=\"legend\">").unwrap();
+            for (_, label, color) in SERIES {
+                write!(html, "<span style=\"background: {color}\"></span>{label}").unwrap();
+            }
+            writeln!(html, "</p>").unwrap();
+            html.push_str(&svg);
+        }
+        None => writeln!(html, "<p>No timings have been stored yet.</p>").unwrap(),
+    }
+
+    writeln!(html, "</body>").unwrap();
+    writeln!(html, "</html>").unwrap();
+    html
+}
+
+/// Writes the report to a file, see [`render`].
+pub fn write(path: &Path, year: Year, timings: &Timings) -> io::Result<()> {
+    fs::write(path, render(year, timings))
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Maps durations onto the width of the plot, spanning whole decades.
+struct LogScale {
+    min_exponent: i32,
+    max_exponent: i32,
+    width: f64,
+}
+
+impl LogScale {
+    #[allow(clippy::cast_possible_truncation)]
+    fn new(min_nanos: f64, max_nanos: f64, width: f64) -> Self {
+        let min_exponent = min_nanos.max(1.0).log10().floor() as i32;
+        let max_exponent = (max_nanos.max(1.0).log10().ceil() as i32).max(min_exponent + 1);
+
+        LogScale {
+            min_exponent,
+            max_exponent,
+            width,
+        }
+    }
+
+    fn x(&self, nanos: f64) -> f64 {
+        let decades = f64::from(self.max_exponent - self.min_exponent);
+        let offset = nanos.max(1.0).log10() - f64::from(self.min_exponent);
+        (offset / decades * self.width).clamp(0.0, self.width)
+    }
+
+    fn decades(&self) -> impl Iterator<Item = i32> {
+        self.min_exponent..=self.max_exponent
+    }
+}
+
+fn render_chart(timings: &Timings) -> Option<String> {
+    let rows: Vec<_> = timings
+        .data
+        .iter()
+        .map(|timing| {
+            let bars: Vec<_> = SERIES
+                .iter()
+                .filter_map(|(part, label, color)| {
+                    timing
+                        .part_nanos(*part)
+                        .map(|nanos| (*label, *color, nanos))
+                })
+                .collect();
+            (timing.day, bars)
+        })
+        .filter(|(_, bars)| !bars.is_empty())
+        .collect();
+
+    let all_nanos = rows.iter().flat_map(|(_, bars)| bars.iter().map(|b| b.2));
+    let min_nanos = all_nanos.clone().reduce(f64::min)?;
+    let max_nanos = all_nanos.reduce(f64::max)?;
+
+    let plot_width = WIDTH - LABEL_WIDTH - 2.0 * MARGIN;
+    let scale = LogScale::new(min_nanos, max_nanos, plot_width);
+    let left = MARGIN + LABEL_WIDTH;
+
+    #[allow(clippy::cast_precision_loss)]
+    let bar_count = rows.iter().map(|(_, bars)| bars.len()).sum::<usize>() as f64;
+    #[allow(clippy::cast_precision_loss)]
+    let plot_height = bar_count * (BAR_HEIGHT + BAR_GAP) + rows.len() as f64 * DAY_GAP;
+    let height = plot_height + AXIS_HEIGHT + MARGIN;
+
+    let mut svg = String::new();
+    writeln!(
+        svg,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{WIDTH}\" height=\"{height}\" viewBox=\"0 0 {WIDTH} {height}\">"
+    )
+    .unwrap();
+
+    for exponent in scale.decades() {
+        let x = left + scale.x(10_f64.powi(exponent));
+        writeln!(
+            svg,
+            "<line class=\"grid\" x1=\"{x:.1}\" y1=\"{MARGIN}\" x2=\"{x:.1}\" y2=\"{:.1}\"/>",
+            MARGIN + plot_height
+        )
+        .unwrap();
+        writeln!(
+            svg,
+            "<text x=\"{x:.1}\" y=\"{:.1}\" text-anchor=\"middle\">{}</text>",
+            MARGIN + plot_height + AXIS_HEIGHT / 2.0,
+            format_decade(exponent)
+        )
+        .unwrap();
+    }
+
+    let mut y = MARGIN + DAY_GAP / 2.0;
+    for (day, bars) in &rows {
+        #[allow(clippy::cast_precision_loss)]
+        let group_height = bars.len() as f64 * (BAR_HEIGHT + BAR_GAP);
+        writeln!(
+            svg,
+            "<text x=\"{MARGIN}\" y=\"{:.1}\" dominant-baseline=\"middle\">Day {day}</text>",
+            y + group_height / 2.0
+        )
+        .unwrap();
+
+        for (label, color, nanos) in bars {
+            writeln!(
+                svg,
+                "<rect x=\"{left}\" y=\"{y:.1}\" width=\"{:.1}\" height=\"{BAR_HEIGHT}\" fill=\"{color}\"><title>Day {day}, {label}: {}</title></rect>",
+                scale.x(*nanos).max(1.0),
+                format_nanos(*nanos)
+            )
+            .unwrap();
+            y += BAR_HEIGHT + BAR_GAP;
+        }
+
+        y += DAY_GAP;
+    }
+
+    writeln!(svg, "</svg>").unwrap();
+    Some(svg)
+}
+
+/// Labels a tick of the axis, e.g. `10µs` for an exponent of 4.
+fn format_decade(exponent: i32) -> String {
+    const UNITS: [&str; 4] = ["ns", "µs", "ms", "s"];
+
+    let unit = exponent.div_euclid(3).clamp(0, 3);
+    let power = (exponent - unit * 3).max(0).unsigned_abs();
+    format!(
+        "{}{}",
+        10_u64.pow(power),
+        UNITS[unit.unsigned_abs() as usize]
+    )
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn format_nanos(nanos: f64) -> String {
+    format!("{:.1?}", Duration::from_nanos(nanos as u64))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_decade, render, LogScale};
+    use crate::{
+        day,
+        template::timings::{Timing, Timings},
+        year,
+    };
+
+    fn timing(parse: Option<&str>, part_1: &str, part_2: Option<&str>) -> Timing {
+        Timing {
+            parse: parse.map(Into::into),
+            part_1: Some(part_1.into()),
+            part_2: part_2.map(Into::into),
+            ..Timing::new(day!(1))
+        }
+    }
+
+    #[test]
+    fn scales_logarithmically() {
+        let scale = LogScale::new(1500.0, 2_000_000.0, 400.0);
+
+        assert_eq!(scale.decades().collect::<Vec<_>>(), vec![3, 4, 5, 6, 7]);
+        assert_eq!(scale.x(1000.0), 0.0);
+        assert_eq!(scale.x(100_000.0), 200.0);
+        assert_eq!(scale.x(10_000_000.0), 400.0);
+        assert_eq!(LogScale::new(50.0, 50.0, 1.0).decades().count(), 2);
+    }
+
+    #[test]
+    fn labels_decades() {
+        assert_eq!(format_decade(0), "1ns");
+        assert_eq!(format_decade(4), "10µs");
+        assert_eq!(format_decade(8), "100ms");
+        assert_eq!(format_decade(10), "10s");
+    }
+
+    #[test]
+    fn renders_a_bar_per_part() {
+        let mut second = timing(None, "1.5ms", None);
+        second.day = day!(2);
+        let timings = Timings {
+            data: vec![timing(Some("20.0µs"), "300µs", Some("4ms")), second],
+        };
+
+        let html = render(year!(2023), &timings);
+
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(!html.contains("<script") && !html.contains("src="));
+        assert_eq!(html.matches("<rect").count(), 4);
+        assert!(html.contains("<title>Day 01, Parse: 20.0µs</title>"));
+        assert!(html.contains("<title>Day 02, Part 1: 1.5ms</title>"));
+        assert!(html.contains(">10µs</text>"));
+        assert!(html.contains(">10ms</text>"));
+    }
+
+    #[test]
+    fn renders_empty_timings() {
+        let html = render(year!(2023), &Timings::default());
+
+        assert!(html.contains("No timings have been stored yet."));
+        assert!(!html.contains("<svg"));
+    }
+}
+
+/* -------------------------------------------------------------------------- */
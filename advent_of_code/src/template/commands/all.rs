@@ -0,0 +1,29 @@
+use std::process;
+use std::time::Duration;
+
+use crate::template::output;
+use crate::template::report::{self, Format};
+use crate::template::run_multi::{run_multi, Runner};
+use crate::template::runner::RunOptions;
+use crate::template::{all_days, Year, ANSI_BOLD, ANSI_RESET};
+
+pub fn handle(year: Year, runner: Runner, jobs: usize, timeout: Option<Duration>, format: Format) {
+    if format != Format::Text {
+        output::redirect_to_stderr();
+    }
+
+    let options = RunOptions {
+        is_timed: false,
+        timeout,
+    };
+    let run = run_multi(year, &all_days().collect(), runner, options, jobs);
+    report::print(format, year, &run.results);
+
+    if !run.regressions.is_empty() {
+        eprintln!("\n{ANSI_BOLD}Wrong answers:{ANSI_RESET}");
+        for (day, part) in run.regressions {
+            eprintln!("Day {day}, part {part}");
+        }
+        process::exit(1);
+    }
+}
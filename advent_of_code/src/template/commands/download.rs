@@ -0,0 +1,55 @@
+use crate::template::{inputs, Puzzle};
+use std::process;
+
+/// Downloads the puzzle description, and the input unless a usable one is stored already.
+pub fn handle(puzzle: Puzzle) {
+    let include_input = !inputs::is_cached(puzzle);
+    if !include_input {
+        println!(
+            "Input is cached in \"{}\", only downloading the puzzle description.",
+            puzzle.input_path().display()
+        );
+    }
+
+    download(puzzle, include_input);
+
+    let recorded = if include_input {
+        inputs::record(puzzle)
+    } else {
+        inputs::record_if_missing(puzzle)
+    };
+
+    if let Err(e) = recorded {
+        eprintln!("failed to verify input: {e}");
+        process::exit(1);
+    }
+
+    #[cfg(feature = "encrypted-inputs")]
+    if let Err(e) = crate::template::encryption::encrypt_input(puzzle) {
+        eprintln!("failed to encrypt input: {e}");
+        process::exit(1);
+    }
+}
+
+#[cfg(not(feature = "http-client"))]
+fn download(puzzle: Puzzle, include_input: bool) {
+    use crate::template::aoc_cli;
+
+    if aoc_cli::check().is_err() {
+        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+        process::exit(1);
+    }
+
+    if let Err(e) = aoc_cli::download(puzzle, include_input) {
+        eprintln!("failed to call aoc-cli: {e}");
+        process::exit(1);
+    };
+}
+
+#[cfg(feature = "http-client")]
+fn download(puzzle: Puzzle, include_input: bool) {
+    if let Err(e) = crate::template::aoc_client::download(puzzle, include_input) {
+        eprintln!("failed to download puzzle: {e}");
+        process::exit(1);
+    };
+}
@@ -0,0 +1,26 @@
+use std::process;
+
+use crate::template::Puzzle;
+
+#[cfg(not(feature = "http-client"))]
+pub fn handle(puzzle: Puzzle) {
+    use crate::template::aoc_cli;
+
+    if aoc_cli::check().is_err() {
+        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+        process::exit(1);
+    }
+
+    if let Err(e) = aoc_cli::read(puzzle) {
+        eprintln!("failed to call aoc-cli: {e}");
+        process::exit(1);
+    };
+}
+
+#[cfg(feature = "http-client")]
+pub fn handle(puzzle: Puzzle) {
+    if let Err(e) = crate::template::aoc_client::read(puzzle) {
+        eprintln!("failed to read puzzle: {e}");
+        process::exit(1);
+    };
+}
@@ -0,0 +1,143 @@
+use std::{
+    fs::{self, File, OpenOptions},
+    io::Write,
+    path::Path,
+    process,
+};
+
+use crate::template::{year_dir, Puzzle};
+
+const MODULE_TEMPLATE: &str =
+    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
+
+fn safe_create_file(path: &Path) -> Result<File, std::io::Error> {
+    OpenOptions::new().write(true).create_new(true).open(path)
+}
+
+fn create_file(path: &Path) -> Result<File, std::io::Error> {
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
+}
+
+/// Declares the module in the year's `days/mod.rs` and adds it to the `days!` invocation,
+/// keeping both sorted.
+fn register_module(registry_path: &Path, module: &str) -> Result<(), String> {
+    let registry = fs::read_to_string(registry_path).map_err(|e| e.to_string())?;
+
+    let invocation = registry
+        .find("advent_of_code::days! {")
+        .ok_or("could not find the `days!` invocation.")?;
+    let start = invocation + "advent_of_code::days! {".len();
+    let end = start
+        + registry[start..]
+            .find('}')
+            .ok_or("could not find the end of the `days!` invocation.")?;
+
+    let mut modules: Vec<&str> = registry[start..end]
+        .split(',')
+        .map(str::trim)
+        .filter(|m| !m.is_empty())
+        .collect();
+
+    if modules.contains(&module) {
+        return Ok(());
+    }
+
+    modules.push(module);
+    modules.sort_unstable();
+
+    // declare the module before the first one that sorts after it, or after the last one.
+    let declaration = format!("pub mod {module};\n");
+    let mut insert_at = 0;
+    let mut offset = 0;
+    for line in registry[..invocation].split_inclusive('\n') {
+        if line.starts_with("pub mod ") {
+            if line > declaration.as_str() {
+                insert_at = offset;
+                break;
+            }
+            insert_at = offset + line.len();
+        }
+        offset += line.len();
+    }
+
+    let list: String = modules.iter().map(|m| format!("\n    {m},")).collect();
+    let updated = format!(
+        "{}{declaration}{}{list}\n{}",
+        &registry[..insert_at],
+        &registry[insert_at..start],
+        &registry[end..]
+    );
+
+    fs::write(registry_path, updated).map_err(|e| e.to_string())
+}
+
+pub fn handle(puzzle: Puzzle) {
+    let Puzzle { year, day } = puzzle;
+    let input_path = puzzle.input_path();
+    let example_path = puzzle.example_path();
+    let module_name = format!("day_{day}");
+    let module_path = puzzle.module_path();
+    let registry_path = year_dir(year).join("src").join("days").join("mod.rs");
+
+    let mut file = match safe_create_file(&module_path) {
+        Ok(file) => file,
+        Err(e) => {
+            eprintln!("Failed to create module file: {e}");
+            process::exit(1);
+        }
+    };
+
+    match file.write_all(
+        MODULE_TEMPLATE
+            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
+            .as_bytes(),
+    ) {
+        Ok(()) => {
+            println!("Created module file \"{}\"", module_path.display());
+        }
+        Err(e) => {
+            eprintln!("Failed to write module contents: {e}");
+            process::exit(1);
+        }
+    }
+
+    match register_module(&registry_path, &module_name) {
+        Ok(()) => {
+            println!(
+                "Registered module \"{module_name}\" in \"{}\"",
+                registry_path.display()
+            );
+        }
+        Err(e) => {
+            eprintln!("Failed to register module: {e}");
+            process::exit(1);
+        }
+    }
+
+    match create_file(&input_path) {
+        Ok(_) => {
+            println!("Created empty input file \"{}\"", input_path.display());
+        }
+        Err(e) => {
+            eprintln!("Failed to create input file: {e}");
+            process::exit(1);
+        }
+    }
+
+    match create_file(&example_path) {
+        Ok(_) => {
+            println!("Created empty example file \"{}\"", example_path.display());
+        }
+        Err(e) => {
+            eprintln!("Failed to create example file: {e}");
+            process::exit(1);
+        }
+    }
+
+    println!("---");
+    println!("🎄 Type `cargo solve --year {year} {day}` to run your solution.");
+}
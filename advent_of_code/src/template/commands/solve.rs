@@ -0,0 +1,109 @@
+use std::io::{BufRead, BufReader};
+use std::process::{self, Command, Stdio};
+use std::time::Duration;
+
+use crate::template::report::{self, Format};
+use crate::template::runner::results_from_json;
+use crate::template::{year_dir, year_package, Puzzle};
+
+pub fn handle(
+    puzzle: Puzzle,
+    release: bool,
+    dhat: bool,
+    submit_part: Option<u8>,
+    wait: bool,
+    timeout: Option<Duration>,
+    format: Format,
+) {
+    let mut cmd_args = vec![
+        "run".to_string(),
+        "-p".to_string(),
+        year_package(puzzle.year),
+    ];
+
+    let mut features = vec![];
+
+    if dhat {
+        cmd_args.extend(["--profile".to_string(), "dhat".to_string()]);
+        features.push("dhat-heap");
+    } else if release {
+        cmd_args.push("--release".to_string());
+    }
+
+    // the solutions binary submits answers, so it needs to use the same client.
+    if cfg!(feature = "http-client") {
+        features.push("advent_of_code/http-client");
+    }
+
+    // it also reads the input, which may be encrypted.
+    if cfg!(feature = "encrypted-inputs") {
+        features.push("advent_of_code/encrypted-inputs");
+    }
+
+    // dhat replaces the counting allocator, so allocations are only counted without it.
+    if cfg!(feature = "count-allocs") && !dhat {
+        features.push("advent_of_code/count-allocs");
+    }
+
+    if !features.is_empty() {
+        cmd_args.push("--features".to_string());
+        cmd_args.push(features.join(","));
+    }
+
+    cmd_args.push("--".to_string());
+    cmd_args.push(puzzle.day.to_string());
+
+    // the solutions binary reads `0` as no timeout.
+    cmd_args.push("--timeout".to_string());
+    cmd_args.push(timeout.map_or(0, |timeout| timeout.as_secs()).to_string());
+
+    // solutions may print to stdout themselves, so the report is built from the record that `--json` emits
+    // on the last line, and everything before it is printed to stderr.
+    let is_report = format != Format::Text;
+    if is_report {
+        cmd_args.push("--json".to_string());
+    }
+
+    if let Some(submit_part) = submit_part {
+        cmd_args.push("--submit".to_string());
+        cmd_args.push(submit_part.to_string());
+
+        if wait {
+            cmd_args.push("--wait".to_string());
+        }
+    }
+
+    let mut cmd = Command::new("cargo")
+        .args(&cmd_args)
+        .current_dir(year_dir(puzzle.year))
+        .stdout(if is_report {
+            Stdio::piped()
+        } else {
+            Stdio::inherit()
+        })
+        .stderr(Stdio::inherit())
+        .spawn()
+        .unwrap();
+
+    if let Some(stdout) = cmd.stdout.take() {
+        let mut last_line: Option<String> = None;
+        for line in BufReader::new(stdout).lines() {
+            if let Some(previous) = last_line.replace(line.unwrap()) {
+                eprintln!("{previous}");
+            }
+        }
+
+        match last_line.as_deref().map(results_from_json) {
+            Some(Ok(results)) => report::print(format, puzzle.year, &[(puzzle.day, results)]),
+            Some(Err(e)) => eprintln!("Failed to read the results of the solution: {e}"),
+            None => {}
+        }
+    }
+
+    let status = cmd.wait().unwrap();
+
+    // e.g. the solution produced a wrong answer.
+    if !status.success() {
+        process::exit(status.code().unwrap_or(1));
+    }
+}
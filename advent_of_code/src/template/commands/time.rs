@@ -0,0 +1,154 @@
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::process;
+use std::time::Duration;
+
+use crate::template::history::{self, format_history, History, ASCII_SPARKS, SPARKS};
+use crate::template::output::{self, errln, outln};
+use crate::template::report::{self, Format};
+use crate::template::run_multi::{run_multi, Runner};
+use crate::template::runner::RunOptions;
+use crate::template::timings::Timings;
+use crate::template::{all_days, chart, readme_benchmarks, Day, Year, ANSI_BOLD, ANSI_RESET};
+
+/// Parts that got slower by more than this many percent compared to the stored timings fail the run.
+pub const DEFAULT_THRESHOLD: f64 = 10.0;
+
+#[allow(clippy::too_many_arguments)]
+pub fn handle(
+    year: Year,
+    runner: Runner,
+    day: Option<Day>,
+    run_all: bool,
+    store: bool,
+    accept: bool,
+    threshold: f64,
+    timeout: Option<Duration>,
+    format: Format,
+    report: Option<PathBuf>,
+) {
+    if format != Format::Text {
+        output::redirect_to_stderr();
+    }
+
+    let stored_timings = Timings::read_from_file(year);
+
+    let days_to_run = day.map_or_else(
+        || {
+            if run_all {
+                all_days().collect()
+            } else {
+                // when the `--all` flag is not set, filter out days that are fully benched.
+                all_days()
+                    .filter(|day| !stored_timings.is_day_complete(*day))
+                    .collect()
+            }
+        },
+        |day| HashSet::from([day]),
+    );
+
+    let options = RunOptions {
+        is_timed: true,
+        timeout,
+    };
+    let run = run_multi(year, &days_to_run, runner, options, 1);
+    let timings = run.timings.unwrap();
+    let is_regressed = compare(&timings, &stored_timings, threshold);
+
+    let merged_timings = stored_timings.merge(&timings);
+    // NOTE: read before anything is written, so the files written below don't mark the commit dirty.
+    let commit = history::current_commit();
+
+    if let Some(path) = report {
+        match chart::write(&path, year, &merged_timings) {
+            Ok(()) => outln!("Wrote benchmark report to \"{}\".", path.display()),
+            Err(e) => errln!("Failed to write benchmark report: {e}"),
+        }
+    }
+
+    if store && is_regressed && !accept {
+        eprintln!("\nNot storing timings that got slower, pass `--accept` to store them anyway.");
+    } else if store {
+        if let Err(e) = merged_timings.store_file(year) {
+            errln!("Failed to store timings: {e}");
+            process::exit(1);
+        }
+
+        if let Err(e) = History::append_file(year, &timings, commit) {
+            errln!("Failed to append to benchmark history: {e}");
+        }
+
+        outln!();
+        match readme_benchmarks::update(year, merged_timings) {
+            Ok(()) => {
+                outln!("Stored updated benchmarks.");
+            }
+            Err(e) => {
+                eprintln!("Failed to store updated benchmarks: {e}");
+            }
+        }
+    }
+
+    report::print(format, year, &run.results);
+
+    if is_regressed {
+        process::exit(1);
+    }
+}
+
+/// Prints the timings recorded for a day each time benchmarks were stored.
+/// With `ascii`, the sparklines are drawn without block characters.
+pub fn history(year: Year, day: Day, ascii: bool) {
+    let history = match History::read_from_file(year) {
+        Ok(history) => history,
+        Err(e) => {
+            eprintln!("Could not read history: {e}");
+            process::exit(1);
+        }
+    };
+    let entries = history.day(day);
+
+    if entries.is_empty() {
+        println!("No timings of day {day} have been stored yet.");
+        return;
+    }
+
+    println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+    println!("------");
+    let sparks = if ascii { ASCII_SPARKS } else { SPARKS };
+    println!("{}", format_history(&entries, &sparks));
+}
+
+/// Prints how the fresh timings compare to the stored ones. Returns whether any part regressed beyond the threshold.
+fn compare(timings: &Timings, stored_timings: &Timings, threshold: f64) -> bool {
+    let changes = timings.compare(stored_timings);
+    if changes.is_empty() {
+        return false;
+    }
+
+    outln!("\n{ANSI_BOLD}Compared to stored timings (threshold {threshold}%):{ANSI_RESET}");
+    for change in &changes {
+        if change.is_regression(threshold) {
+            outln!("{change} slower");
+        } else if change.is_improvement(threshold) {
+            outln!("{change} faster");
+        } else {
+            outln!("{change}");
+        }
+    }
+
+    let regressions: Vec<_> = changes
+        .iter()
+        .filter(|c| c.is_regression(threshold))
+        .collect();
+
+    if !regressions.is_empty() {
+        eprintln!("\n{ANSI_BOLD}Slower than stored timings:{ANSI_RESET}");
+        for change in regressions {
+            eprintln!("Day {}, {}", change.day, change.label());
+        }
+        return true;
+    }
+
+    false
+}
@@ -0,0 +1,243 @@
+/// Tracks when answers may be submitted again, since the server imposes a wait after wrong answers.
+use std::{
+    collections::HashMap,
+    fs,
+    io::{self, ErrorKind},
+    path::PathBuf,
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use tinyjson::JsonValue;
+
+use crate::template::{year_dir, Day, Year};
+
+/// Cooldowns are stored alongside the data files of each year.
+fn get_cooldowns_path(year: Year) -> PathBuf {
+    year_dir(year).join("data").join("cooldowns.json")
+}
+
+/// Represents the wait reported after the last submission for a part of a day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cooldown {
+    pub day: Day,
+    pub part: u8,
+    /// Seconds since the unix epoch.
+    pub submitted_at: u64,
+    pub wait_secs: u64,
+}
+
+/// Represents the cooldowns for a set of days.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Cooldowns {
+    pub data: Vec<Cooldown>,
+}
+
+/// Returns the current time as seconds since the unix epoch.
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+impl Cooldowns {
+    /// Dehydrate cooldowns to a JSON file.
+    pub fn store_file(&self, year: Year) -> Result<(), io::Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(get_cooldowns_path(year))?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate cooldowns from a JSON file. If not present, returns empty cooldowns.
+    /// Fails if the file exists but can't be read, so it does not get overwritten with empty cooldowns.
+    pub fn read_from_file(year: Year) -> Result<Self, String> {
+        match fs::read_to_string(get_cooldowns_path(year)) {
+            Ok(s) => Cooldowns::try_from(s),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Cooldowns::default()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    /// Returns the time left until a part may be submitted again, `None` if it may be submitted now.
+    pub fn remaining(&self, day: Day, part: u8, now: u64) -> Option<Duration> {
+        let cooldown = self.data.iter().find(|c| c.day == day && c.part == part)?;
+
+        let ready_at = cooldown.submitted_at + cooldown.wait_secs;
+        if ready_at > now {
+            Some(Duration::from_secs(ready_at - now))
+        } else {
+            None
+        }
+    }
+
+    /// Records the wait reported after submitting a part.
+    pub fn set(&mut self, day: Day, part: u8, now: u64, wait: Duration) {
+        self.clear(day, part);
+        self.data.push(Cooldown {
+            day,
+            part,
+            submitted_at: now,
+            wait_secs: wait.as_secs(),
+        });
+        self.data.sort_unstable_by_key(|c| (c.day, c.part));
+    }
+
+    /// Forgets the cooldown of a part, e.g. after it has been solved.
+    pub fn clear(&mut self, day: Day, part: u8) {
+        self.data.retain(|c| c.day != day || c.part != part);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Cooldowns> for JsonValue {
+    fn from(value: Cooldowns) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Cooldowns {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Cooldowns {
+            data: json_data
+                .iter()
+                .map(Cooldown::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Cooldown> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &Cooldown) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert(
+            "submitted_at".into(),
+            JsonValue::Number(value.submitted_at as f64),
+        );
+        map.insert(
+            "wait_secs".into(),
+            JsonValue::Number(value.wait_secs as f64),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Cooldown {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected cooldown to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected cooldown.day to be a Day struct.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .ok_or("Expected cooldown.part to be a number.")?;
+
+        let submitted_at = json
+            .get("submitted_at")
+            .and_then(|v| v.get::<f64>())
+            .ok_or("Expected cooldown.submitted_at to be a number.")?;
+
+        let wait_secs = json
+            .get("wait_secs")
+            .and_then(|v| v.get::<f64>())
+            .ok_or("Expected cooldown.wait_secs to be a number.")?;
+
+        Ok(Cooldown {
+            day,
+            part: *part as u8,
+            submitted_at: *submitted_at as u64,
+            wait_secs: *wait_secs as u64,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use crate::day;
+
+    use super::Cooldowns;
+
+    #[test]
+    fn tracks_remaining_wait() {
+        let mut cooldowns = Cooldowns::default();
+        cooldowns.set(day!(1), 1, 1000, Duration::from_secs(60));
+
+        assert_eq!(
+            cooldowns.remaining(day!(1), 1, 1015),
+            Some(Duration::from_secs(45))
+        );
+        assert_eq!(cooldowns.remaining(day!(1), 1, 1060), None);
+        assert_eq!(cooldowns.remaining(day!(1), 2, 1015), None);
+        assert_eq!(cooldowns.remaining(day!(2), 1, 1015), None);
+    }
+
+    #[test]
+    fn replaces_and_clears_cooldowns() {
+        let mut cooldowns = Cooldowns::default();
+        cooldowns.set(day!(1), 1, 1000, Duration::from_secs(60));
+        cooldowns.set(day!(1), 1, 1010, Duration::from_secs(300));
+
+        assert_eq!(cooldowns.data.len(), 1);
+        assert_eq!(
+            cooldowns.remaining(day!(1), 1, 1010),
+            Some(Duration::from_secs(300))
+        );
+
+        cooldowns.clear(day!(1), 1);
+        assert_eq!(cooldowns.remaining(day!(1), 1, 1010), None);
+    }
+
+    #[test]
+    fn serializes_cooldowns() {
+        let mut cooldowns = Cooldowns::default();
+        cooldowns.set(day!(2), 1, 1_700_000_000, Duration::from_secs(60));
+
+        let json = tinyjson::JsonValue::from(cooldowns.clone())
+            .stringify()
+            .unwrap();
+        let deserialized = Cooldowns::try_from(json).unwrap();
+        assert_eq!(deserialized.data, cooldowns.data);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
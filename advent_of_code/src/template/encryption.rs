@@ -0,0 +1,213 @@
+/// Encryption of puzzle inputs, so they can be committed without publishing them.
+use std::{
+    env,
+    error::Error,
+    fmt::Display,
+    fs,
+    io::{self, ErrorKind},
+    path::{Path, PathBuf},
+};
+
+use chacha20poly1305::{
+    aead::{Aead, AeadCore, KeyInit, OsRng},
+    ChaCha20Poly1305, Key, Nonce,
+};
+
+use crate::template::{inputs::encrypted_path, puzzle::workspace_dir, Puzzle};
+
+pub const KEY_ENV_VAR: &str = "AOC_INPUT_KEY";
+const KEY_FILE: &str = ".input-key";
+const NONCE_LEN: usize = 12;
+
+#[derive(Debug)]
+pub enum EncryptionError {
+    KeyNotFound,
+    InvalidKey,
+    Corrupted,
+    IO(io::Error),
+}
+
+impl From<io::Error> for EncryptionError {
+    fn from(e: io::Error) -> Self {
+        EncryptionError::IO(e)
+    }
+}
+
+impl Error for EncryptionError {}
+
+impl Display for EncryptionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EncryptionError::KeyNotFound => write!(
+                f,
+                "input key not found. Set {KEY_ENV_VAR} or store it in {KEY_FILE} at the root of the workspace."
+            ),
+            EncryptionError::InvalidKey => {
+                write!(f, "input key is not a hex encoded 32 byte key.")
+            }
+            EncryptionError::Corrupted => write!(
+                f,
+                "input could not be decrypted, it is corrupted or was encrypted with another key."
+            ),
+            EncryptionError::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+/// Loads the key from the environment, or from the key file at the root of the workspace.
+pub fn load_key() -> Result<Key, EncryptionError> {
+    let hex = match env::var(KEY_ENV_VAR) {
+        Ok(hex) => hex,
+        Err(_) => match fs::read_to_string(get_key_path()) {
+            Ok(hex) => hex,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Err(EncryptionError::KeyNotFound),
+            Err(e) => return Err(e.into()),
+        },
+    };
+
+    let bytes = from_hex(hex.trim()).ok_or(EncryptionError::InvalidKey)?;
+    if bytes.len() != 32 {
+        return Err(EncryptionError::InvalidKey);
+    }
+    Ok(*Key::from_slice(&bytes))
+}
+
+/// Encrypts a text, prefixing the result with the random nonce it was encrypted with.
+#[must_use]
+pub fn encrypt(key: &Key, plaintext: &str) -> Vec<u8> {
+    let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = ChaCha20Poly1305::new(key)
+        .encrypt(&nonce, plaintext.as_bytes())
+        .expect("input fits into a single message");
+
+    let mut data = nonce.to_vec();
+    data.extend(ciphertext);
+    data
+}
+
+/// Decrypts a text encrypted with [`encrypt`].
+pub fn decrypt(key: &Key, data: &[u8]) -> Result<String, EncryptionError> {
+    if data.len() < NONCE_LEN {
+        return Err(EncryptionError::Corrupted);
+    }
+
+    let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+    let plaintext = ChaCha20Poly1305::new(key)
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .or(Err(EncryptionError::Corrupted))?;
+
+    String::from_utf8(plaintext).or(Err(EncryptionError::Corrupted))
+}
+
+/// Reads and decrypts an encrypted file.
+pub fn read(path: &Path) -> Result<String, EncryptionError> {
+    decrypt(&load_key()?, &fs::read(path)?)
+}
+
+/// Replaces the plain text input of a puzzle by its encrypted form. Creates a key if there is none yet.
+pub fn encrypt_input(puzzle: Puzzle) -> Result<(), EncryptionError> {
+    let input_path = puzzle.input_path();
+    let input = match fs::read_to_string(&input_path) {
+        Ok(input) if !input.is_empty() => input,
+        Ok(_) => return Ok(()),
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(e.into()),
+    };
+
+    let encrypted_path = encrypted_path(&input_path);
+    fs::write(&encrypted_path, encrypt(&load_or_create_key()?, &input))?;
+    fs::remove_file(&input_path)?;
+
+    println!(
+        "🔒 Successfully encrypted input to \"{}\".",
+        encrypted_path.display()
+    );
+    Ok(())
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn get_key_path() -> PathBuf {
+    workspace_dir().join(KEY_FILE)
+}
+
+fn load_or_create_key() -> Result<Key, EncryptionError> {
+    match load_key() {
+        Err(EncryptionError::KeyNotFound) => {
+            let key = ChaCha20Poly1305::generate_key(&mut OsRng);
+            let key_path = get_key_path();
+            fs::write(&key_path, to_hex(&key))?;
+            println!(
+                "🔑 Created a new input key in \"{}\". Keep it safe, encrypted inputs can't be read without it.",
+                key_path.display()
+            );
+            Ok(key)
+        }
+        result => result,
+    }
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn from_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) {
+        return None;
+    }
+
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use chacha20poly1305::Key;
+
+    use super::{decrypt, encrypt, from_hex, to_hex, EncryptionError};
+
+    #[test]
+    fn round_trips_inputs() {
+        let key = Key::from([7; 32]);
+        let encrypted = encrypt(&key, "1721\n979\n");
+
+        assert!(!encrypted.windows(4).any(|w| w == b"1721"));
+        assert_eq!(decrypt(&key, &encrypted).unwrap(), "1721\n979\n");
+    }
+
+    #[test]
+    fn rejects_other_keys_and_tampering() {
+        let key = Key::from([7; 32]);
+        let mut encrypted = encrypt(&key, "1721\n979\n");
+
+        assert!(matches!(
+            decrypt(&Key::from([8; 32]), &encrypted),
+            Err(EncryptionError::Corrupted)
+        ));
+
+        let last = encrypted.len() - 1;
+        encrypted[last] ^= 1;
+        assert!(matches!(
+            decrypt(&key, &encrypted),
+            Err(EncryptionError::Corrupted)
+        ));
+        assert!(matches!(
+            decrypt(&key, &[1, 2, 3]),
+            Err(EncryptionError::Corrupted)
+        ));
+    }
+
+    #[test]
+    fn encodes_keys_as_hex() {
+        assert_eq!(to_hex(&[0, 15, 255]), "000fff");
+        assert_eq!(from_hex("000fff"), Some(vec![0, 15, 255]));
+        assert_eq!(from_hex("0g"), None);
+        assert_eq!(from_hex("000"), None);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
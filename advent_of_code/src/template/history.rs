@@ -0,0 +1,384 @@
+/// Append-only history of benchmark timings, to follow how the runtime of a day evolves across rewrites.
+use std::{
+    collections::HashMap,
+    env, fs,
+    io::{self, ErrorKind},
+    path::PathBuf,
+    process::Command,
+    str::FromStr,
+    time::Duration,
+};
+use tinyjson::JsonValue;
+
+use crate::template::{
+    cooldowns, puzzle::workspace_dir, runner::PARSE_PART, timings::Timing, timings::Timings,
+    year_dir, Day, Year,
+};
+
+pub const MACHINE_ENV_VAR: &str = "AOC_MACHINE";
+/// Block characters for sparklines, from lowest to highest.
+pub const SPARKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+/// Fallback for terminals and fonts without block characters.
+pub const ASCII_SPARKS: [char; 8] = ['_', '.', '-', '~', '=', '+', '*', '#'];
+
+/// History is stored alongside the data files of each year.
+fn get_history_path(year: Year) -> PathBuf {
+    year_dir(year).join("data").join("history.json")
+}
+
+/// The timing of a day as measured by a single run.
+#[derive(Clone, Debug)]
+pub struct HistoryEntry {
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    /// The commit that was checked out, suffixed with `-dirty` if there were uncommitted changes.
+    pub commit: Option<String>,
+    pub machine: String,
+    pub timing: Timing,
+}
+
+/// Represents the history of timings of a set of days, in the order they were recorded.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct History {
+    pub data: Vec<HistoryEntry>,
+}
+
+impl History {
+    /// Dehydrate history to a JSON file.
+    pub fn store_file(&self, year: Year) -> Result<(), io::Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(get_history_path(year))?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate history from a JSON file. If not present, returns an empty history.
+    pub fn read_from_file(year: Year) -> Result<Self, String> {
+        match fs::read_to_string(get_history_path(year)) {
+            Ok(s) => History::try_from(s),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(History::default()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    /// Appends the timings of a run to the history file, labeled with `commit` and the current machine.
+    /// The commit is passed in as it has to be read before the timings are stored, see [`current_commit`].
+    /// Nothing is written if the existing history can't be read, so it is never replaced by the new run alone.
+    pub fn append_file(
+        year: Year,
+        timings: &Timings,
+        commit: Option<String>,
+    ) -> Result<(), String> {
+        let mut history =
+            History::read_from_file(year).map_err(|e| format!("could not read history: {e}"))?;
+        let timestamp = cooldowns::now();
+        let machine = machine_label();
+
+        history
+            .data
+            .extend(timings.data.iter().map(|timing| HistoryEntry {
+                timestamp,
+                commit: commit.clone(),
+                machine: machine.clone(),
+                timing: timing.clone(),
+            }));
+
+        history.store_file(year).map_err(|e| e.to_string())
+    }
+
+    /// Returns the entries of a day, oldest first.
+    pub fn day(&self, day: Day) -> Vec<&HistoryEntry> {
+        self.data.iter().filter(|e| e.timing.day == day).collect()
+    }
+}
+
+/// Labels the machine with [`MACHINE_ENV_VAR`], falling back to the hostname.
+fn machine_label() -> String {
+    env::var(MACHINE_ENV_VAR)
+        .ok()
+        .or_else(|| command_output("hostname", &[]))
+        .unwrap_or_else(|| "unknown".into())
+}
+
+/// Returns the checked out commit, suffixed with `-dirty` if there are uncommitted changes.
+/// The data files are ignored, as storing timings changes them.
+pub fn current_commit() -> Option<String> {
+    let commit = command_output("git", &["rev-parse", "--short", "HEAD"])?;
+    let is_dirty =
+        command_output("git", &["status", "--porcelain", "--", ".", ":!*/data/*"]).is_some();
+
+    Some(if is_dirty {
+        format!("{commit}-dirty")
+    } else {
+        commit
+    })
+}
+
+/// Returns the trimmed output of a successful command, `None` if it failed or printed nothing.
+fn command_output(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program)
+        .args(args)
+        .current_dir(workspace_dir())
+        .output()
+        .ok()?;
+
+    let stdout = String::from_utf8(output.stdout).ok()?;
+    let stdout = stdout.trim();
+    (output.status.success() && !stdout.is_empty()).then(|| stdout.to_string())
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Renders the history of a day as a table, followed by a sparkline per part drawn with `sparks`.
+pub fn format_history(entries: &[&HistoryEntry], sparks: &[char]) -> String {
+    let format_part = |entry: &HistoryEntry, part: u8| {
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        entry.timing.part_nanos(part).map_or_else(
+            || "-".into(),
+            |nanos| format!("{:.1?}", Duration::from_nanos(nanos as u64)),
+        )
+    };
+
+    let mut lines = vec![format!(
+        "{:<16}  {:<14}  {:<16}  {:>10}  {:>10}  {:>10}",
+        "Date", "Commit", "Machine", "Parse", "Part 1", "Part 2"
+    )];
+
+    for entry in entries {
+        lines.push(format!(
+            "{:<16}  {:<14}  {:<16}  {:>10}  {:>10}  {:>10}",
+            format_timestamp(entry.timestamp),
+            entry.commit.as_deref().unwrap_or("-"),
+            entry.machine,
+            format_part(entry, PARSE_PART),
+            format_part(entry, 1),
+            format_part(entry, 2),
+        ));
+    }
+
+    lines.push(String::new());
+    for (part, label) in [(PARSE_PART, "Parse:"), (1, "Part 1:"), (2, "Part 2:")] {
+        let values: Vec<f64> = entries
+            .iter()
+            .filter_map(|e| e.timing.part_nanos(part))
+            .collect();
+        if !values.is_empty() {
+            lines.push(format!("{label} {}", sparkline(&values, sparks)));
+        }
+    }
+
+    lines.join("\n")
+}
+
+/// Draws values as a line of `sparks`, e.g. [`SPARKS`], scaled between their minimum and maximum.
+#[must_use]
+pub fn sparkline(values: &[f64], sparks: &[char]) -> String {
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+
+    values
+        .iter()
+        .map(|value| {
+            if max > min {
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                let level = ((value - min) / (max - min) * (sparks.len() - 1) as f64).round();
+                sparks[level as usize]
+            } else {
+                sparks[sparks.len() / 2]
+            }
+        })
+        .collect()
+}
+
+/// Formats seconds since the unix epoch as an UTC date and time, e.g. `2023-12-01 05:00`.
+fn format_timestamp(timestamp: u64) -> String {
+    let days = timestamp / 86_400;
+    let minutes = timestamp % 86_400 / 60;
+
+    // NOTE: converts days since the epoch to a civil date, see http://howardhinnant.github.io/date_algorithms.html
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02}",
+        minutes / 60,
+        minutes % 60
+    )
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<History> for JsonValue {
+    fn from(value: History) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for History {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(History {
+            data: json_data
+                .iter()
+                .map(HistoryEntry::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&HistoryEntry> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &HistoryEntry) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+        map.insert(
+            "commit".into(),
+            match &value.commit {
+                Some(commit) => JsonValue::String(commit.clone()),
+                None => JsonValue::Null,
+            },
+        );
+        map.insert("machine".into(), JsonValue::String(value.machine.clone()));
+        map.insert("timing".into(), JsonValue::from(&value.timing));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for HistoryEntry {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected history entry to be a JSON object.")?;
+
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>())
+            .ok_or("Expected entry.timestamp to be a number.")?;
+
+        let commit = json
+            .get("commit")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected entry.commit to be null or string.")?;
+
+        let machine = json
+            .get("machine")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected entry.machine to be a string.")?;
+
+        let timing = json
+            .get("timing")
+            .ok_or("Expected entry.timing to be a timing.")
+            .map(Timing::try_from)??;
+
+        Ok(HistoryEntry {
+            timestamp: *timestamp as u64,
+            commit: commit.cloned(),
+            machine: machine.clone(),
+            timing,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use crate::{day, template::timings::Timing};
+
+    use super::{
+        format_history, format_timestamp, sparkline, History, HistoryEntry, ASCII_SPARKS, SPARKS,
+    };
+
+    fn entry(timestamp: u64, day: u8, part_1: &str) -> HistoryEntry {
+        HistoryEntry {
+            timestamp,
+            commit: Some("abc1234".into()),
+            machine: "laptop".into(),
+            timing: Timing {
+                part_1: Some(part_1.into()),
+                ..Timing::new(crate::template::Day::new(day).unwrap())
+            },
+        }
+    }
+
+    #[test]
+    fn round_trips_history() {
+        let history = History {
+            data: vec![
+                entry(1_701_406_800, 1, "10.0ms"),
+                entry(1_701_406_800, 2, "1.0s"),
+            ],
+        };
+
+        let json = tinyjson::JsonValue::from(history).stringify().unwrap();
+        let deserialized = History::try_from(json).unwrap();
+
+        assert_eq!(deserialized.data.len(), 2);
+        assert_eq!(deserialized.data[0].timestamp, 1_701_406_800);
+        assert_eq!(deserialized.data[0].commit.as_deref(), Some("abc1234"));
+        assert_eq!(deserialized.data[1].timing.part_1.as_deref(), Some("1.0s"));
+        assert_eq!(deserialized.day(day!(2)).len(), 1);
+    }
+
+    #[test]
+    fn formats_timestamps() {
+        assert_eq!(format_timestamp(0), "1970-01-01 00:00");
+        assert_eq!(format_timestamp(1_701_406_800), "2023-12-01 05:00");
+        assert_eq!(format_timestamp(1_709_164_800), "2024-02-29 00:00");
+    }
+
+    #[test]
+    fn draws_sparklines() {
+        assert_eq!(sparkline(&[1.0, 8.0, 4.5], &SPARKS), "▁█▅");
+        assert_eq!(sparkline(&[3.0, 3.0], &SPARKS), "▅▅");
+        assert_eq!(sparkline(&[1.0, 8.0, 4.5], &ASCII_SPARKS), "_#=");
+    }
+
+    #[test]
+    fn formats_history() {
+        let first = entry(1_701_406_800, 1, "10.0ms");
+        let second = entry(1_701_493_200, 1, "5.0ms");
+        let formatted = format_history(&[&first, &second], &SPARKS);
+
+        assert!(formatted.contains("2023-12-02 05:00  abc1234"));
+        assert!(formatted.contains("5.0ms"));
+        assert!(formatted.ends_with("Part 1: █▁"));
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,374 @@
+/// Integrity checks for the inputs in `data/inputs`, which are downloaded once and then kept.
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs,
+    io::{self, ErrorKind},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+use tinyjson::JsonValue;
+
+use crate::template::{output::errln, year_dir, Day, Puzzle, Year};
+
+/// Markers of the pages the server serves instead of an input, e.g. once the session cookie expired.
+const ERROR_PAGE_MARKERS: [&str; 4] = [
+    "<!doctype",
+    "<html",
+    "puzzle inputs differ by user",
+    "please log in",
+];
+
+const ENCRYPTED_INPUT_ERROR: &str =
+    "input is encrypted, enable the `encrypted-inputs` feature to read it.";
+
+/// Checksums are stored alongside the data files of each year.
+fn get_checksums_path(year: Year) -> PathBuf {
+    year_dir(year).join("data").join("checksums.json")
+}
+
+/// Returns the path of the encrypted form of a file, e.g. `01.txt.enc` for `01.txt`.
+#[must_use]
+pub fn encrypted_path(path: &Path) -> PathBuf {
+    let mut encrypted_path = path.as_os_str().to_owned();
+    encrypted_path.push(".enc");
+    encrypted_path.into()
+}
+
+/// Reads a stored input. With the `encrypted-inputs` feature, its encrypted form is read instead if present.
+pub fn read(path: &Path) -> io::Result<String> {
+    let encrypted_path = encrypted_path(path);
+
+    #[cfg(feature = "encrypted-inputs")]
+    if encrypted_path.exists() {
+        return crate::template::encryption::read(&encrypted_path).map_err(io::Error::other);
+    }
+
+    match fs::read_to_string(path) {
+        Err(e) if e.kind() == ErrorKind::NotFound && encrypted_path.exists() => {
+            Err(io::Error::other(ENCRYPTED_INPUT_ERROR))
+        }
+        Ok(s) if s.is_empty() && encrypted_path.exists() => {
+            Err(io::Error::other(ENCRYPTED_INPUT_ERROR))
+        }
+        result => result,
+    }
+}
+
+/// A reason for an input not to be trusted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InputProblem {
+    Empty,
+    ErrorPage,
+    Truncated,
+    Modified,
+}
+
+impl Display for InputProblem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputProblem::Empty => write!(f, "is empty"),
+            InputProblem::ErrorPage => write!(
+                f,
+                "looks like an error page, the session cookie may have expired"
+            ),
+            InputProblem::Truncated => {
+                write!(f, "does not end with a line break, it may be truncated")
+            }
+            InputProblem::Modified => write!(f, "has changed since it was downloaded"),
+        }
+    }
+}
+
+/// Checks freshly downloaded content. Inputs served by the server always end with a line break.
+pub fn validate(input: &str) -> Result<(), InputProblem> {
+    check_content(input)?;
+
+    if input.ends_with('\n') {
+        Ok(())
+    } else {
+        Err(InputProblem::Truncated)
+    }
+}
+
+/// Checks a stored input before it gets solved, comparing it to the checksum recorded on download.
+pub fn check(puzzle: Puzzle, input: &str) -> Result<(), InputProblem> {
+    check_content(input)?;
+
+    let checksums = Checksums::read_from_file(puzzle.year).unwrap_or_else(|e| {
+        errln!("Warning: could not read checksums: {e}");
+        Checksums::default()
+    });
+
+    match checksums.get(puzzle.day) {
+        Some(expected) if expected != checksum(input) => Err(InputProblem::Modified),
+        _ => Ok(()),
+    }
+}
+
+/// Prints a warning if a stored input is not to be trusted, see [`check`].
+pub fn warn_if_suspicious(puzzle: Puzzle, input: &str) {
+    if let Err(problem) = check(puzzle, input) {
+        errln!("Warning: the input of {puzzle} {problem}.");
+    }
+}
+
+/// Returns whether a usable input is stored, in which case it does not need to be downloaded again.
+pub fn is_cached(puzzle: Puzzle) -> bool {
+    match read(&puzzle.input_path()) {
+        Ok(input) => match check_content(&input) {
+            Ok(()) => true,
+            Err(InputProblem::Empty) => false,
+            Err(problem) => {
+                println!("Stored input {problem}, downloading it again.");
+                false
+            }
+        },
+        Err(_) => false,
+    }
+}
+
+/// Validates the stored input and records its checksum. Inputs that fail validation are removed,
+/// so they don't get mistaken for a cached input.
+pub fn record(puzzle: Puzzle) -> Result<(), String> {
+    let input_path = puzzle.input_path();
+    let input = fs::read_to_string(&input_path).map_err(|e| e.to_string())?;
+
+    if let Err(problem) = validate(&input) {
+        fs::remove_file(&input_path).map_err(|e| e.to_string())?;
+        return Err(format!("downloaded input {problem}."));
+    }
+
+    let mut checksums = Checksums::read_from_file(puzzle.year)
+        .map_err(|e| format!("could not read checksums: {e}"))?;
+    checksums.set(puzzle.day, checksum(&input));
+    checksums.store_file(puzzle.year).map_err(|e| e.to_string())
+}
+
+/// Records the checksum of a stored input if none is known yet.
+pub fn record_if_missing(puzzle: Puzzle) -> Result<(), String> {
+    let mut checksums = Checksums::read_from_file(puzzle.year)
+        .map_err(|e| format!("could not read checksums: {e}"))?;
+    if checksums.get(puzzle.day).is_some() {
+        return Ok(());
+    }
+
+    let input = read(&puzzle.input_path()).map_err(|e| e.to_string())?;
+    checksums.set(puzzle.day, checksum(&input));
+    checksums.store_file(puzzle.year).map_err(|e| e.to_string())
+}
+
+fn check_content(input: &str) -> Result<(), InputProblem> {
+    if input.trim().is_empty() {
+        return Err(InputProblem::Empty);
+    }
+
+    let head = input.chars().take(512).collect::<String>().to_lowercase();
+    if ERROR_PAGE_MARKERS
+        .iter()
+        .any(|marker| head.contains(marker))
+    {
+        return Err(InputProblem::ErrorPage);
+    }
+
+    Ok(())
+}
+
+/// Computes the 64-bit FNV-1a hash of an input as a hex string.
+/// Not cryptographic, but stable across platforms and toolchains which is all that's needed to spot edits.
+#[must_use]
+pub fn checksum(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    });
+    format!("{hash:016x}")
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Represents the checksum of the input of a single day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Checksum {
+    pub day: Day,
+    pub checksum: String,
+}
+
+/// Represents the checksums of the inputs of a set of days.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Checksums {
+    pub data: Vec<Checksum>,
+}
+
+impl Checksums {
+    /// Dehydrate checksums to a JSON file.
+    pub fn store_file(&self, year: Year) -> Result<(), io::Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(get_checksums_path(year))?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate checksums from a JSON file. If not present, returns empty checksums.
+    /// Fails if the file exists but can't be read, so it does not get overwritten with empty checksums.
+    pub fn read_from_file(year: Year) -> Result<Self, String> {
+        match fs::read_to_string(get_checksums_path(year)) {
+            Ok(s) => Checksums::try_from(s),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Checksums::default()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    /// Returns the recorded checksum of a day's input.
+    pub fn get(&self, day: Day) -> Option<&str> {
+        self.data
+            .iter()
+            .find(|c| c.day == day)
+            .map(|c| c.checksum.as_str())
+    }
+
+    /// Records the checksum of a day's input, replacing the previous one.
+    pub fn set(&mut self, day: Day, checksum: String) {
+        match self.data.binary_search_by_key(&day, |c| c.day) {
+            Ok(index) => self.data[index].checksum = checksum,
+            Err(index) => self.data.insert(index, Checksum { day, checksum }),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Checksums> for JsonValue {
+    fn from(value: Checksums) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Checksums {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Checksums {
+            data: json_data
+                .iter()
+                .map(Checksum::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Checksum> for JsonValue {
+    fn from(value: &Checksum) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("checksum".into(), JsonValue::String(value.checksum.clone()));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Checksum {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected checksum to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected checksum.day to be a Day struct.")?;
+
+        let checksum = json
+            .get("checksum")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected checksum.checksum to be a string.")?
+            .clone();
+
+        Ok(Checksum { day, checksum })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use crate::day;
+
+    use super::{checksum, validate, Checksums, InputProblem};
+
+    #[test]
+    fn accepts_puzzle_inputs() {
+        assert_eq!(validate("1721\n979\n366\n"), Ok(()));
+    }
+
+    #[test]
+    fn rejects_broken_inputs() {
+        assert_eq!(validate(""), Err(InputProblem::Empty));
+        assert_eq!(validate("\n\n"), Err(InputProblem::Empty));
+        assert_eq!(validate("1721\n97"), Err(InputProblem::Truncated));
+        assert_eq!(
+            validate("Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"),
+            Err(InputProblem::ErrorPage)
+        );
+        assert_eq!(
+            validate("<!DOCTYPE html>\n<html lang=\"en-us\">\n</html>\n"),
+            Err(InputProblem::ErrorPage)
+        );
+    }
+
+    #[test]
+    fn computes_stable_checksums() {
+        assert_eq!(checksum(""), "cbf29ce484222325");
+        assert_eq!(checksum("a"), "af63dc4c8601ec8c");
+        assert_ne!(checksum("1721\n979\n"), checksum("1721\n978\n"));
+    }
+
+    #[test]
+    fn replaces_checksums() {
+        let mut checksums = Checksums::default();
+        checksums.set(day!(2), "b".into());
+        checksums.set(day!(1), "a".into());
+        checksums.set(day!(2), "c".into());
+
+        assert_eq!(checksums.data.len(), 2);
+        assert_eq!(checksums.data[0].day, day!(1));
+        assert_eq!(checksums.get(day!(2)), Some("c"));
+        assert_eq!(checksums.get(day!(3)), None);
+    }
+
+    #[test]
+    fn serializes_checksums() {
+        let mut checksums = Checksums::default();
+        checksums.set(day!(1), checksum("1721\n"));
+
+        let json = tinyjson::JsonValue::from(checksums.clone())
+            .stringify()
+            .unwrap();
+        let deserialized = Checksums::try_from(json).unwrap();
+        assert_eq!(deserialized.data, checksums.data);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
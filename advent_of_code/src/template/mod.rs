@@ -0,0 +1,149 @@
+use std::env;
+
+#[cfg(all(feature = "count-allocs", not(feature = "dhat-heap")))]
+pub mod alloc;
+pub mod answers;
+pub mod aoc_cli;
+#[cfg(feature = "http-client")]
+pub mod aoc_client;
+pub mod commands;
+pub mod cooldowns;
+#[cfg(feature = "encrypted-inputs")]
+pub mod encryption;
+pub mod inputs;
+pub mod runner;
+
+pub use day::*;
+pub use puzzle::*;
+pub use year::*;
+
+mod chart;
+mod day;
+mod history;
+pub mod output;
+mod puzzle;
+mod readme_benchmarks;
+pub mod report;
+pub mod run_multi;
+pub mod stats;
+mod timings;
+mod year;
+
+pub const ANSI_ITALIC: &str = "\x1b[3m";
+pub const ANSI_BOLD: &str = "\x1b[1m";
+pub const ANSI_RESET: &str = "\x1b[0m";
+
+/// Helper function that reads a text file to a string.
+/// Encrypted files (`NN.txt.enc`) are decrypted if the `encrypted-inputs` feature is enabled.
+#[must_use]
+pub fn read_file(folder: &str, day: Day) -> String {
+    let cwd = env::current_dir().unwrap();
+    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
+    let f = inputs::read(&filepath);
+    f.expect("could not open input file")
+}
+
+/// Helper function that reads the input of a puzzle to a string.
+#[must_use]
+pub fn read_input(puzzle: Puzzle) -> String {
+    let f = inputs::read(&puzzle.input_path());
+    f.expect("could not open input file")
+}
+
+/// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
+#[must_use]
+pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
+    let cwd = env::current_dir().unwrap();
+    let filepath = cwd
+        .join("data")
+        .join(folder)
+        .join(format!("{day}-{part}.txt"));
+    let f = inputs::read(&filepath);
+    f.expect("could not open input file")
+}
+
+/// Creates the constant `DAY` and registers the runner for each part as the constant `SOLUTION`.
+///
+/// The optional parameter `parse` declares that the input is parsed once by a function `parse(&str)`,
+/// whose output is passed by reference to both parts. The parse step is timed separately.
+///
+/// The optional, last parameter (1 or 2) allows you to only run a single part of the solution.
+#[macro_export]
+macro_rules! solution {
+    ($day:expr) => {
+        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+    };
+    ($day:expr, 1) => {
+        $crate::solution!(@impl $day, [part_one, 1]);
+    };
+    ($day:expr, 2) => {
+        $crate::solution!(@impl $day, [part_two, 2]);
+    };
+    ($day:expr, parse) => {
+        $crate::solution!(@impl_parse $day, [part_one, 1] [part_two, 2]);
+    };
+    ($day:expr, parse, 1) => {
+        $crate::solution!(@impl_parse $day, [part_one, 1]);
+    };
+    ($day:expr, parse, 2) => {
+        $crate::solution!(@impl_parse $day, [part_two, 2]);
+    };
+
+    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
+        $crate::solution!(@register $day);
+
+        /// Runs each part of the solution against `input`, verifying the results against `answers`.
+        pub fn solve(
+            puzzle: $crate::template::Puzzle,
+            input: &str,
+            options: $crate::template::runner::RunOptions,
+            answers: &$crate::template::answers::Answers,
+        ) -> Vec<$crate::template::runner::PartResult> {
+            use $crate::template::runner::*;
+            let input: std::sync::Arc<str> = input.into();
+            vec![$( run_part($func, input.clone(), puzzle, $part, options, answers), )*]
+        }
+    };
+
+    (@impl_parse $day:expr, $( [$func:expr, $part:expr] )*) => {
+        $crate::solution!(@register $day);
+
+        /// Parses `input` once, then runs each part of the solution against the parsed input,
+        /// verifying the results against `answers`.
+        pub fn solve(
+            puzzle: $crate::template::Puzzle,
+            input: &str,
+            options: $crate::template::runner::RunOptions,
+            answers: &$crate::template::answers::Answers,
+        ) -> Vec<$crate::template::runner::PartResult> {
+            use $crate::template::runner::*;
+            let (parsed, parse_result) = run_parse(parse, input.into(), options);
+            let Some(parsed) = parsed else {
+                return vec![parse_result];
+            };
+            vec![parse_result, $( run_part(|parsed| $func(parsed), parsed.clone(), puzzle, $part, options, answers), )*]
+        }
+    };
+
+    (@register $day:expr) => {
+        /// The current day.
+        pub const DAY: $crate::template::Day = $crate::day!($day);
+
+        /// Registers this solution with the runner, see [`days!`]($crate::days).
+        pub const SOLUTION: $crate::template::runner::Solution =
+            $crate::template::runner::Solution { day: DAY, solve };
+    };
+}
+
+/// Registers the solution modules of a year with the runner.
+///
+/// Each module is declared with a plain `pub mod` item and is expected to invoke [`solution!`].
+/// The registered solutions are collected into a static `SOLUTIONS` table, which lets the runner
+/// call them without spawning a process.
+#[macro_export]
+macro_rules! days {
+    ($( $module:ident ),* $(,)?) => {
+        /// The solutions of this year, ordered by day.
+        pub static SOLUTIONS: &[$crate::template::runner::Solution] = &[$( $module::SOLUTION, )*];
+    };
+}
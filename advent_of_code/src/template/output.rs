@@ -0,0 +1,126 @@
+/// Output of the runner, which can be buffered per thread so days that run in parallel are printed in order,
+/// or moved to stderr to keep stdout free for a machine-readable report.
+/// Only output printed through the macros of this module is affected, not what solutions print themselves,
+/// which is why the CLI runs days isolated when they run in parallel or stdout is reserved for a report.
+use std::{
+    cell::RefCell,
+    fmt::{Arguments, Write as _},
+    io::{stdout, Write as _},
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+/// Output that was buffered by [`capture`].
+#[derive(Clone, Debug, Default)]
+pub struct Captured {
+    pub stdout: String,
+    pub stderr: String,
+}
+
+impl Captured {
+    /// Writes the buffered output to stdout and stderr.
+    pub fn print(&self) {
+        if is_redirected() {
+            eprint!("{}", self.stdout);
+        } else {
+            print!("{}", self.stdout);
+            let _ = stdout().flush();
+        }
+        eprint!("{}", self.stderr);
+    }
+}
+
+static REDIRECTED: AtomicBool = AtomicBool::new(false);
+
+/// Prints all further output meant for stdout to stderr instead.
+pub fn redirect_to_stderr() {
+    REDIRECTED.store(true, Ordering::Relaxed);
+}
+
+fn is_redirected() -> bool {
+    REDIRECTED.load(Ordering::Relaxed)
+}
+
+thread_local! {
+    static CAPTURED: RefCell<Option<Captured>> = const { RefCell::new(None) };
+}
+
+/// Runs a function, buffering the output it prints on the current thread instead of printing it.
+pub fn capture<T>(func: impl FnOnce() -> T) -> (T, Captured) {
+    CAPTURED.with_borrow_mut(|captured| *captured = Some(Captured::default()));
+    let result = func();
+    let captured = CAPTURED.with_borrow_mut(Option::take).unwrap_or_default();
+    (result, captured)
+}
+
+/// Returns whether output of the current thread is being buffered.
+pub fn is_capturing() -> bool {
+    CAPTURED.with_borrow(Option::is_some)
+}
+
+pub fn write_stdout(args: Arguments) {
+    CAPTURED.with_borrow_mut(|captured| match captured {
+        Some(captured) => {
+            let _ = captured.stdout.write_fmt(args);
+        }
+        None if is_redirected() => eprint!("{args}"),
+        None => print!("{args}"),
+    });
+}
+
+pub fn write_stderr(args: Arguments) {
+    CAPTURED.with_borrow_mut(|captured| match captured {
+        Some(captured) => {
+            let _ = captured.stderr.write_fmt(args);
+        }
+        None => eprint!("{args}"),
+    });
+}
+
+/// Like `print!`, but buffered while output is captured.
+macro_rules! out {
+    ($($arg:tt)*) => {
+        $crate::template::output::write_stdout(format_args!($($arg)*))
+    };
+}
+
+/// Like `println!`, but buffered while output is captured.
+macro_rules! outln {
+    () => {
+        $crate::template::output::write_stdout(format_args!("\n"))
+    };
+    ($($arg:tt)*) => {
+        $crate::template::output::write_stdout(format_args!("{}\n", format_args!($($arg)*)))
+    };
+}
+
+/// Like `eprintln!`, but buffered while output is captured.
+macro_rules! errln {
+    ($($arg:tt)*) => {
+        $crate::template::output::write_stderr(format_args!("{}\n", format_args!($($arg)*)))
+    };
+}
+
+pub(crate) use {errln, out, outln};
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{capture, is_capturing};
+
+    #[test]
+    fn buffers_output_per_thread() {
+        let ((), captured) = capture(|| {
+            assert!(is_capturing());
+            out!("Part {}: ", 1);
+            outln!("42");
+            errln!("Warning: {}", "slow");
+        });
+
+        assert!(!is_capturing());
+        assert_eq!(captured.stdout, "Part 1: 42\n");
+        assert_eq!(captured.stderr, "Warning: slow\n");
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,89 @@
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+
+use crate::template::{Day, Year};
+
+/// Identifies a puzzle by its year and day.
+///
+/// # Display
+/// This value displays as `<year>/<day>`, e.g. `2022/08`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Puzzle {
+    pub year: Year,
+    pub day: Day,
+}
+
+impl Puzzle {
+    pub fn new(year: Year, day: Day) -> Self {
+        Self { year, day }
+    }
+
+    /// Path of the puzzle input.
+    #[must_use]
+    pub fn input_path(&self) -> PathBuf {
+        self.data_path("inputs", "txt")
+    }
+
+    /// Path of the example input.
+    #[must_use]
+    pub fn example_path(&self) -> PathBuf {
+        self.data_path("examples", "txt")
+    }
+
+    /// Path of the puzzle description.
+    #[must_use]
+    pub fn description_path(&self) -> PathBuf {
+        self.data_path("puzzles", "md")
+    }
+
+    /// Path of the module that holds the solution.
+    #[must_use]
+    pub fn module_path(&self) -> PathBuf {
+        year_dir(self.year)
+            .join("src")
+            .join("days")
+            .join(format!("day_{}.rs", self.day))
+    }
+
+    fn data_path(&self, folder: &str, extension: &str) -> PathBuf {
+        year_dir(self.year)
+            .join("data")
+            .join(folder)
+            .join(format!("{}.{extension}", self.day))
+    }
+}
+
+#[cfg(feature = "today")]
+impl Puzzle {
+    /// Returns the puzzle of the current day if it's between the 1st and the 25th of december, `None` otherwise.
+    pub fn today() -> Option<Self> {
+        Some(Self::new(Year::today()?, Day::today()?))
+    }
+}
+
+impl Display for Puzzle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.year, self.day)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Returns the workspace directory that holds the solutions, data files and README of a year.
+#[must_use]
+pub fn year_dir(year: Year) -> PathBuf {
+    workspace_dir().join(year.to_string())
+}
+
+/// Returns the name of the cargo package that holds the solutions of a year.
+#[must_use]
+pub fn year_package(year: Year) -> String {
+    format!("advent_of_code_{year}")
+}
+
+pub(crate) fn workspace_dir() -> &'static Path {
+    // NOTE: this library lives in a direct subdirectory of the workspace.
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("library is part of the workspace")
+}
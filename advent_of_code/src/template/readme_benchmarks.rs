@@ -0,0 +1,593 @@
+/// Module that updates the readme me with timing information.
+/// The approach taken is similar to how `aoc-readme-stars` handles this.
+///
+/// The README of a year gets a table of its days. The README of the workspace gets a section per year
+/// that has stored timings. Optional columns are configured with a comment inside the markers,
+/// e.g. `<!--- columns: parse, memory, samples, percent --->`, which is kept when the table is updated.
+use std::{fmt::Display, fs, io, path::Path, str::FromStr, time::Duration};
+
+use crate::template::stats::{format_bytes, Stats};
+use crate::template::timings::{get_timings_path, Timing, Timings};
+use crate::template::{workspace_dir, year_dir, Day, Year};
+
+static MARKER: &str = "<!--- benchmarking table --->";
+static COLUMNS_PREFIX: &str = "<!--- columns:";
+static COMMENT_END: &str = "--->";
+
+/// Number of days listed below the table, slowest first.
+const SLOWEST_DAYS: usize = 3;
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "{e}"),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+/// Optional columns of the table. The parse step is shown before the parts, the others after them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Column {
+    Parse,
+    Memory,
+    /// The number of samples each part was benched with. The samples of the parse step are not shown.
+    Samples,
+    Percent,
+}
+
+impl Column {
+    fn name(self) -> &'static str {
+        match self {
+            Column::Parse => "parse",
+            Column::Memory => "memory",
+            Column::Samples => "samples",
+            Column::Percent => "percent",
+        }
+    }
+
+    fn header(self) -> &'static str {
+        match self {
+            Column::Parse => "Parse",
+            Column::Memory => "Memory",
+            Column::Samples => "Samples",
+            Column::Percent => "% of total",
+        }
+    }
+}
+
+impl FromStr for Column {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "parse" => Ok(Column::Parse),
+            "memory" => Ok(Column::Memory),
+            "samples" => Ok(Column::Samples),
+            "percent" => Ok(Column::Percent),
+            _ => Err(Error::Parser(format!(
+                "unknown benchmark column `{s}`, expected parse, memory, samples or percent."
+            ))),
+        }
+    }
+}
+
+/// The timings of a year, shown as a section of the table.
+pub struct Section {
+    pub year: Year,
+    pub timings: Timings,
+}
+
+pub struct TablePosition {
+    pos_start: usize,
+    pos_end: usize,
+}
+
+#[must_use]
+pub fn get_path_for_day(day: Day) -> String {
+    format!("./src/days/day_{day}.rs")
+}
+
+/// Links are relative to the README, which is in the directory of the year or the workspace.
+fn get_link(year: Year, day: Day, is_workspace: bool) -> String {
+    let path = get_path_for_day(day);
+    if is_workspace {
+        format!("./{year}/{}", path.trim_start_matches("./"))
+    } else {
+        path
+    }
+}
+
+fn locate_table(readme: &str) -> Result<TablePosition, Error> {
+    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+
+    if matches.len() > 2 {
+        return Err(Error::Parser(
+            "{}: too many occurences of marker in README.".into(),
+        ));
+    }
+
+    let pos_start = matches
+        .first()
+        .map(|m| m.0)
+        .ok_or_else(|| Error::Parser("Could not find table start position.".into()))?;
+
+    let pos_end = matches
+        .last()
+        .map(|m| m.0 + m.1.len())
+        .ok_or_else(|| Error::Parser("Could not find table end position.".into()))?;
+
+    Ok(TablePosition { pos_start, pos_end })
+}
+
+/// Reads the columns configured in an existing table, `None` if they are not configured.
+fn read_columns(table: &str) -> Result<Option<Vec<Column>>, Error> {
+    let Some(start) = table.find(COLUMNS_PREFIX) else {
+        return Ok(None);
+    };
+
+    let config = &table[start + COLUMNS_PREFIX.len()..];
+    let end = config
+        .find(COMMENT_END)
+        .ok_or_else(|| Error::Parser("Could not find the end of the columns comment.".into()))?;
+
+    config[..end]
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(Column::from_str)
+        .collect::<Result<_, _>>()
+        .map(Some)
+}
+
+/// Without configuration, the parse step is always shown and the memory usage if allocations were counted.
+fn default_columns(sections: &[Section]) -> Vec<Column> {
+    let has_memory = sections
+        .iter()
+        .flat_map(|s| &s.timings.data)
+        .any(|t| t.memory().is_some());
+
+    if has_memory {
+        vec![Column::Parse, Column::Memory]
+    } else {
+        vec![Column::Parse]
+    }
+}
+
+fn format_cell(column: Column, timing: &Timing, total_nanos: f64) -> String {
+    let samples =
+        |stats: Option<Stats>| stats.map_or_else(|| "-".into(), |s| s.samples.to_string());
+
+    match column {
+        Column::Parse => format!("`{}`", timing.parse.as_deref().unwrap_or("-")),
+        Column::Memory => match timing.memory() {
+            Some(memory) => format!(
+                "`{}` in {} allocations",
+                format_bytes(memory.peak_bytes),
+                memory.allocations
+            ),
+            None => "`-`".into(),
+        },
+        Column::Samples => format!(
+            "`{}` / `{}`",
+            samples(timing.part_1_stats),
+            samples(timing.part_2_stats)
+        ),
+        Column::Percent if total_nanos > 0.0 => {
+            format!("{:.1}%", timing.total_nanos / total_nanos * 100.0)
+        }
+        Column::Percent => "-".into(),
+    }
+}
+
+fn construct_section(
+    section: &Section,
+    columns: &[Column],
+    is_workspace: bool,
+    lines: &mut Vec<String>,
+) {
+    let total_nanos: f64 = section.timings.data.iter().map(|t| t.total_nanos).sum();
+    let has_parse = columns.contains(&Column::Parse);
+    let trailing: Vec<Column> = columns
+        .iter()
+        .copied()
+        .filter(|c| *c != Column::Parse)
+        .collect();
+
+    let mut headers = vec!["Day"];
+    if has_parse {
+        headers.push(Column::Parse.header());
+    }
+    headers.extend(["Part 1", "Part 2"]);
+    headers.extend(trailing.iter().map(|c| c.header()));
+
+    lines.push(format!("| {} |", headers.join(" | ")));
+    lines.push(format!("| {}  |", vec![":---:"; headers.len()].join(" | ")));
+
+    for timing in &section.timings.data {
+        let mut cells = vec![format!(
+            "[Day {}]({})",
+            timing.day.into_inner(),
+            get_link(section.year, timing.day, is_workspace)
+        )];
+        if has_parse {
+            cells.push(format_cell(Column::Parse, timing, total_nanos));
+        }
+        cells.push(format!("`{}`", timing.part_1.as_deref().unwrap_or("-")));
+        cells.push(format!("`{}`", timing.part_2.as_deref().unwrap_or("-")));
+        cells.extend(
+            trailing
+                .iter()
+                .map(|c| format_cell(*c, timing, total_nanos)),
+        );
+
+        lines.push(format!("| {} |", cells.join(" | ")));
+    }
+
+    lines.push(String::new());
+    lines.push(format!(
+        "**Total: {:.2}ms**",
+        section.timings.total_millis()
+    ));
+}
+
+/// Lists the days that took the longest, across all sections.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn construct_slowest_days(sections: &[Section], is_workspace: bool, lines: &mut Vec<String>) {
+    let mut days: Vec<(Year, &Timing)> = sections
+        .iter()
+        .flat_map(|s| s.timings.data.iter().map(|t| (s.year, t)))
+        .filter(|(_, t)| t.total_nanos > 0.0)
+        .collect();
+
+    if days.is_empty() {
+        return;
+    }
+
+    days.sort_by(|a, b| b.1.total_nanos.total_cmp(&a.1.total_nanos));
+
+    lines.push(String::new());
+    lines.push("**Slowest days:**".into());
+    lines.push(String::new());
+
+    for (i, (year, timing)) in days.iter().take(SLOWEST_DAYS).enumerate() {
+        let label = if sections.len() > 1 {
+            format!("{year} Day {}", timing.day.into_inner())
+        } else {
+            format!("Day {}", timing.day.into_inner())
+        };
+
+        lines.push(format!(
+            "{}. [{label}]({}) `{:.1?}`",
+            i + 1,
+            get_link(*year, timing.day, is_workspace),
+            Duration::from_nanos(timing.total_nanos as u64)
+        ));
+    }
+}
+
+/// Constructs the table between the markers, with a heading per year if there is more than one section.
+fn construct_table(
+    prefix: &str,
+    sections: &[Section],
+    configured_columns: Option<&[Column]>,
+    is_workspace: bool,
+) -> String {
+    let header = format!("{prefix} Benchmarks");
+    let columns = configured_columns.map_or_else(|| default_columns(sections), <[Column]>::to_vec);
+
+    let mut lines: Vec<String> = vec![MARKER.into()];
+
+    if let Some(columns) = configured_columns {
+        let names: Vec<&str> = columns.iter().map(|c| c.name()).collect();
+        lines.push(format!(
+            "{COLUMNS_PREFIX} {} {COMMENT_END}",
+            names.join(", ")
+        ));
+    }
+
+    lines.push(header);
+    lines.push(String::new());
+
+    for (i, section) in sections.iter().enumerate() {
+        if sections.len() > 1 {
+            if i > 0 {
+                lines.push(String::new());
+            }
+            lines.push(format!("{prefix}# {}", section.year));
+            lines.push(String::new());
+        }
+
+        construct_section(section, &columns, is_workspace, &mut lines);
+    }
+
+    construct_slowest_days(sections, is_workspace, &mut lines);
+    lines.push(MARKER.into());
+
+    lines.join("\n")
+}
+
+fn update_content(s: &mut String, sections: &[Section], is_workspace: bool) -> Result<(), Error> {
+    let positions = locate_table(s)?;
+    let columns = read_columns(&s[positions.pos_start..positions.pos_end])?;
+    let table = construct_table("##", sections, columns.as_deref(), is_workspace);
+    s.replace_range(positions.pos_start..positions.pos_end, &table);
+    Ok(())
+}
+
+/// Updates the table of a README, returns whether it has one.
+fn update_file(path: &Path, sections: &[Section], is_workspace: bool) -> Result<bool, Error> {
+    if !path.exists() {
+        return Ok(false);
+    }
+
+    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
+    if !readme.contains(MARKER) {
+        return Ok(false);
+    }
+
+    update_content(&mut readme, sections, is_workspace)?;
+    fs::write(path, &readme)?;
+    Ok(true)
+}
+
+/// Returns the years of the workspace that have stored timings, including `year`.
+fn stored_years(year: Year) -> Result<Vec<Year>, Error> {
+    let mut years = vec![year];
+
+    for entry in fs::read_dir(workspace_dir())? {
+        let Some(stored) = entry?
+            .file_name()
+            .to_str()
+            .and_then(|name| name.parse::<Year>().ok())
+        else {
+            continue;
+        };
+
+        if stored != year && get_timings_path(stored).exists() {
+            years.push(stored);
+        }
+    }
+
+    years.sort_unstable();
+    Ok(years)
+}
+
+/// Updates the README of the year and the README of the workspace, whichever have a table.
+pub fn update(year: Year, timings: Timings) -> Result<(), Error> {
+    let is_year_updated = update_file(
+        &year_dir(year).join("README.md"),
+        &[Section {
+            year,
+            timings: timings.clone(),
+        }],
+        false,
+    )?;
+
+    let mut timings = Some(timings);
+    let sections: Vec<Section> = stored_years(year)?
+        .into_iter()
+        .map(|stored| Section {
+            year: stored,
+            timings: if stored == year {
+                timings.take().unwrap_or_default()
+            } else {
+                Timings::read_from_file(stored)
+            },
+        })
+        .collect();
+    let is_workspace_updated = update_file(&workspace_dir().join("README.md"), &sections, true)?;
+
+    if is_year_updated || is_workspace_updated {
+        Ok(())
+    } else {
+        Err(Error::Parser("Could not find table start position.".into()))
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{read_columns, update_content, Column, Section, MARKER};
+    use crate::{
+        day, template::stats::MemoryStats, template::timings::Timing, template::timings::Timings,
+        year,
+    };
+
+    fn get_mock_timings() -> Timings {
+        Timings {
+            data: vec![
+                Timing {
+                    part_1: Some("10ms".into()),
+                    part_2: Some("20ms".into()),
+                    total_nanos: 3e+7,
+                    ..Timing::new(day!(1))
+                },
+                Timing {
+                    parse: Some("5ms".into()),
+                    part_1: Some("30ms".into()),
+                    part_2: Some("40ms".into()),
+                    total_nanos: 7.5e+7,
+                    ..Timing::new(day!(2))
+                },
+                Timing {
+                    part_1: Some("40ms".into()),
+                    part_2: Some("50ms".into()),
+                    total_nanos: 9e+7,
+                    ..Timing::new(day!(4))
+                },
+            ],
+        }
+    }
+
+    fn get_mock_sections() -> Vec<Section> {
+        vec![Section {
+            year: year!(2023),
+            timings: get_mock_timings(),
+        }]
+    }
+
+    #[test]
+    #[should_panic]
+    fn errors_if_marker_not_present() {
+        let mut s = "# readme".to_string();
+        update_content(&mut s, &get_mock_sections(), false).unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn errors_if_too_many_markers_present() {
+        let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
+        update_content(&mut s, &get_mock_sections(), false).unwrap();
+    }
+
+    #[test]
+    fn updates_empty_benchmarks() {
+        let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
+        update_content(&mut s, &get_mock_sections(), false).unwrap();
+        assert_eq!(s.contains("## Benchmarks"), true);
+    }
+
+    #[test]
+    fn updates_existing_benchmarks() {
+        let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
+        update_content(&mut s, &get_mock_sections(), false).unwrap();
+        update_content(&mut s, &get_mock_sections(), false).unwrap();
+        assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
+        assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
+    }
+
+    #[test]
+    fn format_benchmarks() {
+        let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
+        update_content(&mut s, &get_mock_sections(), false).unwrap();
+        let expected = [
+            "foo",
+            "bar",
+            "<!--- benchmarking table --->",
+            "## Benchmarks",
+            "",
+            "| Day | Parse | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: | :---:  |",
+            "| [Day 1](./src/days/day_01.rs) | `-` | `10ms` | `20ms` |",
+            "| [Day 2](./src/days/day_02.rs) | `5ms` | `30ms` | `40ms` |",
+            "| [Day 4](./src/days/day_04.rs) | `-` | `40ms` | `50ms` |",
+            "",
+            "**Total: 195.00ms**",
+            "",
+            "**Slowest days:**",
+            "",
+            "1. [Day 4](./src/days/day_04.rs) `90.0ms`",
+            "2. [Day 2](./src/days/day_02.rs) `75.0ms`",
+            "3. [Day 1](./src/days/day_01.rs) `30.0ms`",
+            "<!--- benchmarking table --->",
+            "baz",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+
+    #[test]
+    fn format_benchmarks_with_memory() {
+        let mut timings = get_mock_timings();
+        timings.data[0].part_1_memory = Some(MemoryStats {
+            peak_bytes: 1024,
+            total_bytes: 4096,
+            allocations: 3,
+        });
+        timings.data[0].part_2_memory = Some(MemoryStats {
+            peak_bytes: 2048,
+            total_bytes: 2048,
+            allocations: 1,
+        });
+        let sections = [Section {
+            year: year!(2023),
+            timings,
+        }];
+
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, &sections, false).unwrap();
+        let lines: Vec<&str> = s.lines().collect();
+
+        assert_eq!(lines[3], "| Day | Parse | Part 1 | Part 2 | Memory |");
+        assert_eq!(
+            lines[5],
+            "| [Day 1](./src/days/day_01.rs) | `-` | `10ms` | `20ms` | `2.0 KiB` in 4 allocations |"
+        );
+        assert_eq!(
+            lines[6],
+            "| [Day 2](./src/days/day_02.rs) | `5ms` | `30ms` | `40ms` | `-` |"
+        );
+    }
+
+    #[test]
+    fn keeps_configured_columns() {
+        let mut s = format!(
+            "{}\n<!--- columns: percent, samples --->\n{}",
+            MARKER, MARKER
+        );
+        update_content(&mut s, &get_mock_sections(), false).unwrap();
+        update_content(&mut s, &get_mock_sections(), false).unwrap();
+        let lines: Vec<&str> = s.lines().collect();
+
+        assert_eq!(lines[1], "<!--- columns: percent, samples --->");
+        assert_eq!(lines[4], "| Day | Part 1 | Part 2 | % of total | Samples |");
+        assert_eq!(
+            lines[6],
+            "| [Day 1](./src/days/day_01.rs) | `10ms` | `20ms` | 15.4% | `-` / `-` |"
+        );
+    }
+
+    #[test]
+    fn reads_columns() {
+        assert_eq!(read_columns("no config").unwrap(), None);
+        assert_eq!(
+            read_columns("<!--- columns: parse,memory --->").unwrap(),
+            Some(vec![Column::Parse, Column::Memory])
+        );
+        assert!(read_columns("<!--- columns: speed --->").is_err());
+        assert!(read_columns("<!--- columns: parse").is_err());
+    }
+
+    #[test]
+    fn format_workspace_benchmarks() {
+        let mut older = get_mock_timings();
+        older.data.truncate(1);
+        older.data[0].total_nanos = 2e+8;
+        let sections = [
+            Section {
+                year: year!(2022),
+                timings: older,
+            },
+            Section {
+                year: year!(2023),
+                timings: get_mock_timings(),
+            },
+        ];
+
+        let mut s = format!("{}{}", MARKER, MARKER);
+        update_content(&mut s, &sections, true).unwrap();
+        let lines: Vec<&str> = s.lines().collect();
+
+        assert_eq!(lines[3], "### 2022");
+        assert_eq!(
+            lines[7],
+            "| [Day 1](./2022/src/days/day_01.rs) | `-` | `10ms` | `20ms` |"
+        );
+        assert_eq!(lines[9], "**Total: 200.00ms**");
+        assert_eq!(lines[11], "### 2023");
+        assert!(s.contains("1. [2022 Day 1](./2022/src/days/day_01.rs) `200.0ms`"));
+        assert!(s.contains("2. [2023 Day 4](./2023/src/days/day_04.rs) `90.0ms`"));
+    }
+}
@@ -0,0 +1,223 @@
+/// Machine-readable reports of the results of a run, see `--format`.
+use std::{collections::HashMap, fmt::Display, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::runner::PartResult;
+use crate::template::{Day, Year};
+
+/// The format results are printed in. With a format other than text, stdout is reserved for the report
+/// and the usual output is printed to stderr instead. Solutions then run in a separate process,
+/// whose stdout is forwarded to stderr as well.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("unknown format `{s}`, expected json, csv or text.")),
+        }
+    }
+}
+
+impl Display for Format {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Format::Text => write!(f, "text"),
+            Format::Json => write!(f, "json"),
+            Format::Csv => write!(f, "csv"),
+        }
+    }
+}
+
+const CSV_HEADER: &str = "year,day,part,answer,verdict,nanos,samples,failure";
+
+/// Renders the results of a run, returns `None` for the text format which is printed as the run goes.
+/// The parse step of a solution is reported as part `0`.
+#[must_use]
+pub fn render(format: Format, year: Year, results: &[(Day, Vec<PartResult>)]) -> Option<String> {
+    match format {
+        Format::Text => None,
+        Format::Json => Some(to_json(year, results)),
+        Format::Csv => Some(to_csv(year, results)),
+    }
+}
+
+/// Prints the report of a run to stdout, see [`render`].
+pub fn print(format: Format, year: Year, results: &[(Day, Vec<PartResult>)]) {
+    if let Some(report) = render(format, year, results) {
+        println!("{report}");
+    }
+}
+
+/// A single line JSON document: `{ "year": "2023", "results": [{ "day": "01", "part": 1, ... }] }`.
+#[allow(clippy::cast_precision_loss)]
+fn to_json(year: Year, results: &[(Day, Vec<PartResult>)]) -> String {
+    let records = results
+        .iter()
+        .flat_map(|(day, parts)| parts.iter().map(move |part| (day, part)))
+        .map(|(day, part)| {
+            let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+            let optional = |value: &Option<String>| match value {
+                Some(value) => JsonValue::String(value.clone()),
+                None => JsonValue::Null,
+            };
+
+            map.insert("day".into(), JsonValue::String(day.to_string()));
+            map.insert("part".into(), JsonValue::Number(f64::from(part.part)));
+            map.insert("answer".into(), optional(&part.answer));
+            map.insert(
+                "verdict".into(),
+                JsonValue::String(part.verdict.as_str().into()),
+            );
+            map.insert(
+                "nanos".into(),
+                JsonValue::Number(part.duration.as_nanos() as f64),
+            );
+            map.insert("samples".into(), JsonValue::Number(part.samples as f64));
+            map.insert("failure".into(), optional(&part.failure));
+
+            JsonValue::Object(map)
+        })
+        .collect();
+
+    let mut map: HashMap<String, JsonValue> = HashMap::new();
+    map.insert("year".into(), JsonValue::String(year.to_string()));
+    map.insert("results".into(), JsonValue::Array(records));
+
+    JsonValue::Object(map)
+        .stringify()
+        .expect("report is serializable")
+}
+
+fn to_csv(year: Year, results: &[(Day, Vec<PartResult>)]) -> String {
+    let mut lines = vec![CSV_HEADER.to_string()];
+
+    for (day, part) in results
+        .iter()
+        .flat_map(|(day, parts)| parts.iter().map(move |part| (day, part)))
+    {
+        lines.push(
+            [
+                year.to_string(),
+                day.to_string(),
+                part.part.to_string(),
+                csv_field(part.answer.as_deref().unwrap_or_default()),
+                part.verdict.as_str().to_string(),
+                part.duration.as_nanos().to_string(),
+                part.samples.to_string(),
+                csv_field(part.failure.as_deref().unwrap_or_default()),
+            ]
+            .join(","),
+        );
+    }
+
+    lines.join("\n")
+}
+
+/// Quotes a field if it contains a separator, quote or line break (RFC 4180).
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{collections::HashMap, str::FromStr, time::Duration};
+
+    use tinyjson::JsonValue;
+
+    use super::{render, Format};
+    use crate::template::{answers::Verdict, runner::PartResult};
+    use crate::{day, year};
+
+    fn get_mock_results() -> Vec<(crate::template::Day, Vec<PartResult>)> {
+        let part =
+            |part: u8, answer: Option<&str>, verdict: Verdict, failure: Option<&str>| PartResult {
+                part,
+                answer: answer.map(Into::into),
+                verdict,
+                duration: Duration::from_nanos(1500),
+                samples: 1,
+                stats: None,
+                memory: None,
+                failure: failure.map(Into::into),
+            };
+
+        vec![
+            (
+                day!(1),
+                vec![
+                    part(1, Some("54644"), Verdict::Correct, None),
+                    part(2, Some("a,\"b\"\nc"), Verdict::Unknown, None),
+                ],
+            ),
+            (
+                day!(17),
+                vec![part(2, None, Verdict::Wrong, Some("panicked: todo"))],
+            ),
+        ]
+    }
+
+    #[test]
+    fn parses_formats() {
+        assert_eq!(Format::from_str("json"), Ok(Format::Json));
+        assert_eq!(Format::from_str("csv"), Ok(Format::Csv));
+        assert_eq!(Format::from_str("text"), Ok(Format::Text));
+        assert!(Format::from_str("xml").is_err());
+    }
+
+    #[test]
+    fn renders_json() {
+        let report = render(Format::Json, year!(2023), &get_mock_results()).unwrap();
+        assert!(!report.contains('\n'));
+
+        let json = JsonValue::from_str(&report).unwrap();
+        let json: &HashMap<String, JsonValue> = json.get().unwrap();
+        assert_eq!(json["year"], JsonValue::String("2023".into()));
+
+        let records: &Vec<JsonValue> = json["results"].get().unwrap();
+        assert_eq!(records.len(), 3);
+
+        let last: &HashMap<String, JsonValue> = records[2].get().unwrap();
+        assert_eq!(last["day"], JsonValue::String("17".into()));
+        assert_eq!(last["answer"], JsonValue::Null);
+        assert_eq!(last["verdict"], JsonValue::String("wrong".into()));
+        assert_eq!(last["failure"], JsonValue::String("panicked: todo".into()));
+    }
+
+    #[test]
+    fn renders_csv() {
+        let report = render(Format::Csv, year!(2023), &get_mock_results()).unwrap();
+        let expected = [
+            "year,day,part,answer,verdict,nanos,samples,failure",
+            "2023,01,1,54644,correct,1500,1,",
+            "2023,01,2,\"a,\"\"b\"\"\nc\",unknown,1500,1,",
+            "2023,17,2,,wrong,1500,1,panicked: todo",
+        ]
+        .join("\n");
+        assert_eq!(report, expected);
+    }
+
+    #[test]
+    fn renders_nothing_as_text() {
+        assert_eq!(render(Format::Text, year!(2023), &get_mock_results()), None);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
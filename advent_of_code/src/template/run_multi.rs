@@ -0,0 +1,386 @@
+use std::{
+    collections::{BTreeMap, HashSet},
+    fmt::Display,
+    io,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+};
+
+use crate::template::answers::{Answers, Verdict};
+use crate::template::output::{self, errln, outln, Captured};
+use crate::template::runner::{
+    find_solution, read_answers, PartResult, RunOptions, Solution, PARSE_PART,
+};
+use crate::template::{inputs, Day, Puzzle, Year, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+use super::{
+    all_days,
+    timings::{Timing, Timings},
+};
+
+/// Determines how [`run_multi`] executes the solutions of a year.
+#[derive(Clone, Copy)]
+pub enum Runner<'a> {
+    /// Call the registered solutions from within the current process.
+    InProcess(&'a [Solution]),
+    /// Spawn the solutions binary of the year once per day.
+    Isolated { is_release: bool },
+}
+
+/// The outcome of running the solutions of several days.
+pub struct MultiRun {
+    /// Timings of every solved day, only present when running timed.
+    pub timings: Option<Timings>,
+    /// Parts that produced a different result than the accepted answer.
+    pub regressions: Vec<(Day, u8)>,
+    /// The results of every solved day, in day order.
+    pub results: Vec<(Day, Vec<PartResult>)>,
+}
+
+/// Runs the solutions of several days, up to `jobs` of them at the same time.
+/// When running concurrently, the output of each day is buffered and printed in day order.
+/// What solutions print themselves is only buffered with [`Runner::Isolated`].
+pub fn run_multi(
+    year: Year,
+    days_to_run: &HashSet<Day>,
+    runner: Runner,
+    options: RunOptions,
+    jobs: usize,
+) -> MultiRun {
+    let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+    let mut regressions: Vec<(Day, u8)> = vec![];
+    let mut day_results: Vec<(Day, Vec<PartResult>)> = vec![];
+
+    // NOTE: use non-duplicate, sorted day values.
+    let days: Vec<Day> = all_days().filter(|day| days_to_run.contains(day)).collect();
+
+    // isolated days read the answers in their own process.
+    let answers = match runner {
+        Runner::InProcess(_) => read_answers(year),
+        Runner::Isolated { .. } => Answers::default(),
+    };
+
+    let mut collect_results = |day: Day, results: Option<Vec<PartResult>>| {
+        let Some(results) = results else {
+            return;
+        };
+
+        regressions.extend(
+            results
+                .iter()
+                .filter(|r| r.verdict == Verdict::Wrong)
+                .map(|r| (day, r.part)),
+        );
+        timings.push(timing_from_results(day, &results));
+        day_results.push((day, results));
+    };
+
+    // benchmarks of days running side by side would skew each other, so timed runs are always sequential.
+    if options.is_timed || jobs <= 1 {
+        for (index, &day) in days.iter().enumerate() {
+            if index > 0 {
+                outln!();
+            }
+            collect_results(day, run_day(year, day, runner, options, &answers));
+        }
+    } else {
+        run_parallel(
+            &days,
+            jobs,
+            |day| run_day(year, day, runner, options, &answers),
+            |index, day, results, captured| {
+                if index > 0 {
+                    outln!();
+                }
+                captured.print();
+                collect_results(day, results);
+            },
+        );
+    }
+
+    let timings = if options.is_timed {
+        let timings = Timings { data: timings };
+        let total_millis = timings.total_millis();
+        outln!(
+            "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
+        );
+        Some(timings)
+    } else {
+        None
+    };
+
+    MultiRun {
+        timings,
+        regressions,
+        results: day_results,
+    }
+}
+
+/// Runs the days on a pool of `jobs` threads, buffering the output of each day.
+/// Results are passed on in day order, as soon as all earlier days have finished.
+fn run_parallel<T: Send>(
+    days: &[Day],
+    jobs: usize,
+    run: impl Fn(Day) -> T + Sync,
+    mut on_finished: impl FnMut(usize, Day, T, Captured),
+) {
+    let next_day = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(days.len()) {
+            let sender = sender.clone();
+            let (next_day, run) = (&next_day, &run);
+
+            scope.spawn(move || loop {
+                let index = next_day.fetch_add(1, Ordering::Relaxed);
+                let Some(&day) = days.get(index) else {
+                    break;
+                };
+
+                let (result, captured) = output::capture(|| run(day));
+                if sender.send((index, result, captured)).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(sender);
+
+        // hold back days that finish early until every day before them has been printed.
+        let mut finished = BTreeMap::new();
+        let mut next_index = 0;
+
+        for (index, result, captured) in receiver {
+            finished.insert(index, (result, captured));
+
+            while let Some((result, captured)) = finished.remove(&next_index) {
+                on_finished(next_index, days[next_index], result, captured);
+                next_index += 1;
+            }
+        }
+    });
+}
+
+fn run_day(
+    year: Year,
+    day: Day,
+    runner: Runner,
+    options: RunOptions,
+    answers: &Answers,
+) -> Option<Vec<PartResult>> {
+    outln!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+    outln!("------");
+
+    let results = match runner {
+        Runner::InProcess(solutions) => run_in_process(solutions, year, day, options, answers),
+        Runner::Isolated { is_release } => run_isolated(year, day, options, is_release),
+    };
+
+    if results.is_none() {
+        outln!("Not solved.");
+    }
+
+    results
+}
+
+fn run_in_process(
+    solutions: &[Solution],
+    year: Year,
+    day: Day,
+    options: RunOptions,
+    answers: &Answers,
+) -> Option<Vec<PartResult>> {
+    let solution = find_solution(solutions, day)?;
+    let puzzle = Puzzle::new(year, day);
+
+    let input = match inputs::read(&puzzle.input_path()) {
+        Ok(input) => input,
+        Err(e) => {
+            errln!("Failed to read input: {e}");
+            return None;
+        }
+    };
+    inputs::warn_if_suspicious(puzzle, &input);
+
+    Some((solution.solve)(puzzle, &input, options, answers))
+}
+
+fn run_isolated(
+    year: Year,
+    day: Day,
+    options: RunOptions,
+    is_release: bool,
+) -> Option<Vec<PartResult>> {
+    match child_commands::run_solution(year, day, options, is_release) {
+        Ok(results) => results,
+        Err(e) => {
+            errln!("Failed to run solution: {e}");
+            None
+        }
+    }
+}
+
+/// Collects the durations of the parse step and of the parts that produced an answer, unless they failed.
+/// Parts that were not benched, e.g. while a part that timed out was still running, are left out.
+#[allow(clippy::cast_precision_loss)]
+fn timing_from_results(day: Day, results: &[PartResult]) -> Timing {
+    let mut timing = Timing::new(day);
+
+    for result in results.iter().filter(|r| {
+        r.failure.is_none() && r.stats.is_some() && (r.answer.is_some() || r.part == PARSE_PART)
+    }) {
+        let duration = Some(format!("{:.1?}", result.duration));
+
+        match result.part {
+            PARSE_PART => {
+                (timing.parse, timing.parse_stats, timing.parse_memory) =
+                    (duration, result.stats, result.memory);
+            }
+            1 => {
+                (timing.part_1, timing.part_1_stats, timing.part_1_memory) =
+                    (duration, result.stats, result.memory);
+            }
+            2 => {
+                (timing.part_2, timing.part_2_stats, timing.part_2_memory) =
+                    (duration, result.stats, result.memory);
+            }
+            _ => continue,
+        }
+
+        timing.total_nanos += result.duration.as_nanos() as f64;
+    }
+
+    timing
+}
+
+#[derive(Debug)]
+pub enum Error {
+    BrokenPipe,
+    IO(io::Error),
+    Protocol(String),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::BrokenPipe => write!(f, "could not capture the output of the solution."),
+            Error::IO(e) => write!(f, "{e}"),
+            Error::Protocol(e) => write!(f, "could not read the results of the solution: {e}"),
+        }
+    }
+}
+
+/// The solutions of each year are also compiled into a single binary that runs one day at a time.
+/// This module encapsulates interaction with these binaries, both invoking them as well as reading their results.
+pub mod child_commands {
+    use super::Error;
+    use crate::template::output::{self, errln, outln};
+    use crate::template::runner::{results_from_json, PartResult, RunOptions};
+    use crate::template::{year_dir, year_package, Day, Puzzle, Year};
+    use std::{
+        io::{BufRead, BufReader},
+        process::{Command, Stdio},
+        thread,
+    };
+
+    /// Run the solution bin for a given day. Returns `None` if the day has not been solved yet.
+    pub fn run_solution(
+        year: Year,
+        day: Day,
+        options: RunOptions,
+        is_release: bool,
+    ) -> Result<Option<Vec<PartResult>>, Error> {
+        // skip command invocation for days that have not been scaffolded yet.
+        if !Puzzle::new(year, day).module_path().exists() {
+            return Ok(None);
+        }
+
+        let package = year_package(year);
+        let day_padded = day.to_string();
+        let mut args = vec!["run", "--quiet", "-p", &package];
+
+        if is_release {
+            args.push("--release");
+        }
+
+        if cfg!(feature = "encrypted-inputs") {
+            args.extend(["--features", "advent_of_code/encrypted-inputs"]);
+        }
+
+        if cfg!(feature = "count-allocs") {
+            args.extend(["--features", "advent_of_code/count-allocs"]);
+        }
+
+        args.push("--");
+        args.push(&day_padded);
+        args.push("--json");
+
+        if options.is_timed {
+            // mirror `--time` flag to child invocations.
+            args.push("--time");
+        }
+
+        let timeout = options.timeout.map_or(0, |timeout| timeout.as_secs());
+        let timeout = timeout.to_string();
+        args.extend(["--timeout", &timeout]);
+
+        // spawn child command with piped stdout/stderr.
+        // forward output to stdout/stderr while holding back the last line of stdout, which carries the results.
+
+        let mut cmd = Command::new("cargo")
+            .args(&args)
+            .current_dir(year_dir(year))
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+
+        let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
+        let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
+
+        // output is captured per thread, so the forwarding thread hands back stderr when it is captured.
+        let is_capturing = output::is_capturing();
+        let thread = thread::spawn(move || {
+            let mut captured_lines = vec![];
+            stderr.lines().for_each(|line| {
+                if is_capturing {
+                    captured_lines.push(line.unwrap());
+                } else {
+                    eprintln!("{}", line.unwrap());
+                }
+            });
+            captured_lines
+        });
+
+        let mut last_line: Option<String> = None;
+
+        for line in stdout.lines() {
+            if let Some(previous) = last_line.replace(line?) {
+                outln!("{previous}");
+            }
+        }
+
+        for line in thread.join().unwrap() {
+            errln!("{line}");
+        }
+        cmd.wait()?;
+
+        // binaries exit without output for days that have not been solved yet.
+        let Some(last_line) = last_line else {
+            return Ok(None);
+        };
+
+        results_from_json(&last_line).map(Some).map_err(|e| {
+            outln!("{last_line}");
+            Error::Protocol(e)
+        })
+    }
+}
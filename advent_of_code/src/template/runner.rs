@@ -0,0 +1,896 @@
+/// Encapsulates code that interacts with solution functions.
+use std::any::Any;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hint::black_box;
+use std::io::{stdout, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc, Once};
+use std::time::{Duration, Instant};
+use std::{cmp, env, process, thread};
+
+use tinyjson::JsonValue;
+
+use crate::template::answers::{Answers, Verdict, WrongGuess};
+use crate::template::aoc_cli::SubmissionOutcome;
+use crate::template::cooldowns::{self, Cooldowns};
+use crate::template::output::{self, errln, out, outln};
+use crate::template::report::{self, Format};
+use crate::template::stats::{MemoryStats, Stats};
+use crate::template::ANSI_BOLD;
+use crate::template::{inputs, read_input, Day, Puzzle, Year, ANSI_ITALIC, ANSI_RESET};
+
+/// A solution as registered by [`solution!`](crate::solution) and collected by [`days!`](crate::days).
+#[derive(Clone, Copy)]
+pub struct Solution {
+    pub day: Day,
+    /// Runs every part of the solution against an input, verifying the results against the answers.
+    pub solve: fn(Puzzle, &str, RunOptions, &Answers) -> Vec<PartResult>,
+}
+
+/// Determines how the parts of a solution are run.
+#[derive(Clone, Copy, Debug)]
+pub struct RunOptions {
+    /// Bench each part instead of running it once.
+    pub is_timed: bool,
+    /// Parts that don't finish in time are abandoned. Only the first run of a part is limited, not its benchmark.
+    pub timeout: Option<Duration>,
+}
+
+/// The part number of the parse step of solutions that declare one, see [`run_parse`].
+pub const PARSE_PART: u8 = 0;
+
+/// The timeout of a part, unless another one is passed with `--timeout`.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Parts run on a thread of their own. They used to run on the main thread, so they get a stack of the same size.
+const PART_STACK_SIZE: usize = 8 * 1024 * 1024;
+
+/// The name of the threads parts run on, whose panics are reported as a failure instead of by the panic hook.
+const PART_THREAD_NAME: &str = "part";
+
+/// The number of part threads that are still running, including those of parts that timed out.
+static RUNNING_PARTS: AtomicUsize = AtomicUsize::new(0);
+
+static QUIET_PANIC_HOOK: Once = Once::new();
+
+/// Converts the value of `--timeout` in seconds, where `0` disables the timeout.
+#[must_use]
+pub fn timeout_from_secs(secs: Option<u64>) -> Option<Duration> {
+    match secs {
+        None => Some(DEFAULT_TIMEOUT),
+        Some(0) => None,
+        Some(secs) => Some(Duration::from_secs(secs)),
+    }
+}
+
+/// Why a part did not produce a result.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Failure {
+    TimedOut(Duration),
+    Panicked(String),
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Failure::TimedOut(timeout) => write!(f, "timed out after {timeout:?}"),
+            Failure::Panicked(message) => write!(f, "panicked: {message}"),
+        }
+    }
+}
+
+/// The outcome of running a single part of a solution, or its parse step.
+#[derive(Clone, Debug)]
+pub struct PartResult {
+    /// The part number, [`PARSE_PART`] for the parse step.
+    pub part: u8,
+    pub answer: Option<String>,
+    pub verdict: Verdict,
+    /// The median duration if the part was benched.
+    pub duration: Duration,
+    pub samples: u128,
+    /// Only present if the part was benched.
+    pub stats: Option<Stats>,
+    /// Only present if allocations are counted, see the `count-allocs` feature.
+    pub memory: Option<MemoryStats>,
+    /// Why the part did not produce a result, if it timed out or panicked.
+    pub failure: Option<String>,
+}
+
+/// Returns the registered solution for `day`, if that day has been solved.
+#[must_use]
+pub fn find_solution(solutions: &[Solution], day: Day) -> Option<&Solution> {
+    solutions.iter().find(|solution| solution.day == day)
+}
+
+/// Entry point of a year's solutions binary: runs the day passed as the first argument.
+///
+/// With `--json`, the results are emitted as a single JSON record on the last line of stdout,
+/// see [`results_to_json`]. Exits with a non-zero status if any part produced a wrong answer.
+pub fn run_day(year: Year, solutions: &[Solution]) {
+    let Some(day) = env::args().nth(1).and_then(|x| x.parse::<Day>().ok()) else {
+        eprintln!(
+            "Unexpected command-line input. Format: <day> [--time] [--timeout <seconds>] [--format <format>] [--json] [--submit <part>]"
+        );
+        process::exit(1);
+    };
+
+    let Some(solution) = find_solution(solutions, day) else {
+        eprintln!("Day {day} of {year} has not been solved yet.");
+        process::exit(1);
+    };
+
+    let puzzle = Puzzle::new(year, day);
+    let options = RunOptions {
+        is_timed: env::args().any(|x| x == "--time"),
+        timeout: timeout_from_secs(arg_value("--timeout").and_then(|x| x.parse().ok())),
+    };
+
+    let format = match arg_value("--format").map(|x| x.parse::<Format>()) {
+        None => Format::Text,
+        Some(Ok(format)) => format,
+        Some(Err(e)) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+    if format != Format::Text {
+        output::redirect_to_stderr();
+    }
+
+    let input = read_input(puzzle);
+    inputs::warn_if_suspicious(puzzle, &input);
+
+    let answers = read_answers(year);
+    let results = (solution.solve)(puzzle, &input, options, &answers);
+    report::print(format, year, &[(day, results.clone())]);
+
+    if env::args().any(|x| x == "--json") {
+        println!("{}", results_to_json(&results));
+    }
+
+    if results.iter().any(|r| r.verdict == Verdict::Wrong) {
+        process::exit(1);
+    }
+}
+
+/// Returns the value that follows a flag in the command-line arguments.
+fn arg_value(flag: &str) -> Option<String> {
+    env::args().skip_while(|x| x != flag).nth(1)
+}
+
+pub fn run_part<I: ?Sized + Send + Sync + 'static, T: Display + Send + 'static>(
+    func: impl Fn(&I) -> Option<T> + Clone + Send + 'static,
+    input: Arc<I>,
+    puzzle: Puzzle,
+    part: u8,
+    options: RunOptions,
+    answers: &Answers,
+) -> PartResult {
+    let part_str = format!("Part {part}");
+
+    let run = run_timed(func, input, options, |result| {
+        print_result(result, &part_str, "");
+    });
+
+    let Measured {
+        result,
+        duration,
+        stats,
+        memory,
+    } = match run {
+        Ok(measured) => measured,
+        Err(failure) => {
+            let verdict = answers.verify(puzzle.day, part, None);
+            outln!("{part_str}: ✖ {failure} {verdict}");
+            return PartResult::failed(part, verdict, &failure);
+        }
+    };
+
+    let answer = result.as_ref().map(ToString::to_string);
+    let verdict = answers.verify(puzzle.day, part, answer.as_deref());
+
+    print_result(
+        &result,
+        &part_str,
+        &format!(
+            "{} {verdict}",
+            format_duration(&duration, stats.as_ref(), memory.as_ref())
+        ),
+    );
+
+    if let Some(result) = result {
+        submit_result(result, puzzle, part);
+    }
+
+    PartResult {
+        part,
+        answer,
+        verdict,
+        duration,
+        samples: stats.map_or(1, |s| s.samples),
+        stats,
+        memory,
+        failure: None,
+    }
+}
+
+/// Reads the answers to verify results against, once per run. Results can't be verified if they can't be read.
+pub fn read_answers(year: Year) -> Answers {
+    Answers::read_from_file(year).unwrap_or_else(|e| {
+        errln!("Could not read answers: {e}");
+        Answers::default()
+    })
+}
+
+/// Runs the parse step of a solution, whose output is shared by both parts.
+/// It is timed like a part, and reported as a [`PartResult`] without an answer.
+/// Returns no output if the parse step failed, in which case the parts can't run.
+pub fn run_parse<T: Send + 'static>(
+    func: impl Fn(&str) -> T + Clone + Send + 'static,
+    input: Arc<str>,
+    options: RunOptions,
+) -> (Option<Arc<T>>, PartResult) {
+    let run = run_timed(func, input, options, |_| {
+        out!("Parse:");
+    });
+
+    let Measured {
+        result: parsed,
+        duration,
+        stats,
+        memory,
+    } = match run {
+        Ok(measured) => measured,
+        Err(failure) => {
+            outln!("Parse: ✖ {failure}");
+            return (
+                None,
+                PartResult::failed(PARSE_PART, Verdict::Unknown, &failure),
+            );
+        }
+    };
+
+    outln!(
+        "\rParse:{}",
+        format_duration(&duration, stats.as_ref(), memory.as_ref())
+    );
+
+    let result = PartResult {
+        part: PARSE_PART,
+        answer: None,
+        verdict: Verdict::Unknown,
+        duration,
+        samples: stats.map_or(1, |s| s.samples),
+        stats,
+        memory,
+        failure: None,
+    };
+
+    (Some(Arc::new(parsed)), result)
+}
+
+impl PartResult {
+    fn failed(part: u8, verdict: Verdict, failure: &Failure) -> Self {
+        PartResult {
+            part,
+            answer: None,
+            verdict,
+            duration: Duration::ZERO,
+            samples: 0,
+            stats: None,
+            memory: None,
+            failure: Some(failure.to_string()),
+        }
+    }
+}
+
+/// The output of a solution part along with its measurements.
+struct Measured<T> {
+    result: T,
+    duration: Duration,
+    stats: Option<Stats>,
+    memory: Option<MemoryStats>,
+}
+
+/// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
+///  1. in debug, the function is executed once.
+///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+///
+/// The first run happens on a thread of its own, which is abandoned if it exceeds the timeout.
+/// Allocations are counted during the first run only. Parts are not benched while a part that timed out
+/// is still running, as it would skew the benchmark.
+fn run_timed<I: ?Sized + Send + Sync + 'static, T: Send + 'static>(
+    func: impl Fn(&I) -> T + Clone + Send + 'static,
+    input: Arc<I>,
+    options: RunOptions,
+    hook: impl Fn(&T),
+) -> Result<Measured<T>, Failure> {
+    let is_benched = options.is_timed && RUNNING_PARTS.load(Ordering::SeqCst) == 0;
+    if options.is_timed && !is_benched {
+        errln!("Not benching, a part that timed out is still running.");
+    }
+
+    let (result, base_time, memory) = {
+        let (func, input) = (func.clone(), input.clone());
+
+        run_guarded(
+            move || {
+                let timer = Instant::now();
+
+                #[cfg(feature = "dhat-heap")]
+                let _profiler = dhat::Profiler::new_heap();
+
+                let (result, memory) = measure_memory(|| func(&input));
+                (result, timer.elapsed(), memory)
+            },
+            options.timeout,
+        )?
+    };
+
+    hook(&result);
+
+    let (duration, stats) = if is_benched {
+        let stats = bench(func, &input, &base_time);
+        (stats.median_duration(), Some(stats))
+    } else {
+        (base_time, None)
+    };
+
+    Ok(Measured {
+        result,
+        duration,
+        stats,
+        memory,
+    })
+}
+
+/// Runs a function on a thread of its own, catching panics and giving up on it after the timeout.
+/// Threads can't be stopped, so a part that timed out keeps running in the background until it finishes
+/// or the process exits. Run days isolated to have them stopped along with their process.
+fn run_guarded<T: Send + 'static>(
+    func: impl FnOnce() -> T + Send + 'static,
+    timeout: Option<Duration>,
+) -> Result<T, Failure> {
+    install_quiet_panic_hook();
+    let (sender, receiver) = mpsc::channel();
+
+    RUNNING_PARTS.fetch_add(1, Ordering::SeqCst);
+    thread::Builder::new()
+        .name(PART_THREAD_NAME.into())
+        .stack_size(PART_STACK_SIZE)
+        .spawn(move || {
+            let result = panic::catch_unwind(AssertUnwindSafe(func));
+            RUNNING_PARTS.fetch_sub(1, Ordering::SeqCst);
+            let _ = sender.send(result);
+        })
+        .expect("failed to spawn a thread for the part");
+
+    let result = match timeout {
+        Some(timeout) => receiver
+            .recv_timeout(timeout)
+            .map_err(|_| Failure::TimedOut(timeout))?,
+        None => receiver
+            .recv()
+            .map_err(|_| Failure::Panicked("the part exited unexpectedly.".into()))?,
+    };
+
+    result.map_err(|payload| Failure::Panicked(panic_message(payload.as_ref())))
+}
+
+/// Panics of parts are reported along with their result, so the panic hook stays quiet for them.
+fn install_quiet_panic_hook() {
+    QUIET_PANIC_HOOK.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if thread::current().name() != Some(PART_THREAD_NAME) {
+                default_hook(info);
+            }
+        }));
+    });
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        (*message).to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic payload".into()
+    }
+}
+
+#[cfg(all(feature = "count-allocs", not(feature = "dhat-heap")))]
+fn measure_memory<T>(func: impl FnOnce() -> T) -> (T, Option<MemoryStats>) {
+    let (result, memory) = crate::template::alloc::measure(func);
+    (result, Some(memory))
+}
+
+#[cfg(not(all(feature = "count-allocs", not(feature = "dhat-heap"))))]
+fn measure_memory<T>(func: impl FnOnce() -> T) -> (T, Option<MemoryStats>) {
+    (func(), None)
+}
+
+/// Benches a function, after a number of warmup iterations that are not measured.
+fn bench<I: ?Sized, T>(func: impl Fn(&I) -> T, input: &I, base_time: &Duration) -> Stats {
+    let mut stdout = stdout();
+
+    out!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+    let _ = stdout.flush();
+
+    let bench_iterations =
+        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
+    let warmup_iterations = cmp::max(bench_iterations / 10, 1);
+
+    for _ in 0..warmup_iterations {
+        black_box(func(black_box(input)));
+    }
+
+    let mut timers: Vec<Duration> = vec![];
+
+    for _ in 0..bench_iterations {
+        let timer = Instant::now();
+        black_box(func(black_box(input)));
+        timers.push(timer.elapsed());
+    }
+
+    Stats::from_samples(&timers).expect("at least 10 samples are taken")
+}
+
+fn format_duration(
+    duration: &Duration,
+    stats: Option<&Stats>,
+    memory: Option<&MemoryStats>,
+) -> String {
+    let timing = match stats {
+        Some(stats) => stats.to_string(),
+        None => format!("{duration:.1?}"),
+    };
+
+    match memory {
+        Some(memory) => format!(" ({timing}; {memory})"),
+        None => format!(" ({timing})"),
+    }
+}
+
+fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+    let is_intermediate_result = duration_str.is_empty();
+
+    match result {
+        Some(result) => {
+            if result.to_string().contains('\n') {
+                let str = format!("{part}: ▼ {duration_str}");
+                if is_intermediate_result {
+                    out!("{str}");
+                } else {
+                    out!("\r");
+                    outln!("{str}");
+                    outln!("{result}");
+                }
+            } else {
+                let str = format!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}{duration_str}");
+                if is_intermediate_result {
+                    out!("{str}");
+                } else {
+                    out!("\r");
+                    outln!("{str}");
+                }
+            }
+        }
+        None => {
+            if is_intermediate_result {
+                out!("{part}: ✖");
+            } else {
+                out!("\r");
+                outln!("{part}: ✖             ");
+            }
+        }
+    }
+}
+
+/// Parse the arguments passed to `solve` and try to submit one part of the solution if:
+///  1. we are in `--release` mode.
+///  2. aoc-cli is installed, unless the built-in client is enabled with the `http-client` feature.
+///  3. the answer is not already known to be wrong, see [`Answers::check_submission`].
+///  4. the wait imposed after the last submission has elapsed. With `--wait`, this sleeps until then.
+///
+/// The response is recorded in the answers and cooldowns of the year.
+fn submit_result<T: Display>(result: T, puzzle: Puzzle, part: u8) {
+    let args: Vec<String> = env::args().collect();
+
+    if !args.contains(&"--submit".into()) {
+        return;
+    }
+
+    if args.len() < 3 {
+        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
+        process::exit(1);
+    }
+
+    let part_index = args.iter().position(|x| x == "--submit").unwrap() + 1;
+
+    let Ok(part_submit) = args[part_index].parse::<u8>() else {
+        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
+        process::exit(1);
+    };
+
+    if part_submit != part {
+        return;
+    }
+
+    // NOTE: files that can't be read are left alone, storing the response would erase what they hold.
+    let (mut answers, mut cooldowns) = match (
+        Answers::read_from_file(puzzle.year),
+        Cooldowns::read_from_file(puzzle.year),
+    ) {
+        (Ok(answers), Ok(cooldowns)) => (answers, cooldowns),
+        (Err(e), _) => {
+            eprintln!("Refusing to submit: could not read answers: {e}");
+            process::exit(1);
+        }
+        (_, Err(e)) => {
+            eprintln!("Refusing to submit: could not read cooldowns: {e}");
+            process::exit(1);
+        }
+    };
+    let wait = args.contains(&"--wait".into());
+
+    if !submit_and_record(
+        &mut answers,
+        &mut cooldowns,
+        puzzle,
+        part,
+        result.to_string(),
+        wait,
+        submit_answer,
+    ) {
+        return;
+    }
+
+    if let Err(e) = answers.store_file(puzzle.year) {
+        eprintln!("Failed to store answers: {e}");
+    }
+
+    if let Err(e) = cooldowns.store_file(puzzle.year) {
+        eprintln!("Failed to store cooldowns: {e}");
+    }
+}
+
+/// Submits an answer with `submit` unless it is known to be wrong or the part is cooling down, and records
+/// the response in `answers` and `cooldowns`. With `wait`, this sleeps until the cooldown has elapsed instead
+/// of refusing. Returns whether anything was recorded.
+pub(crate) fn submit_and_record(
+    answers: &mut Answers,
+    cooldowns: &mut Cooldowns,
+    puzzle: Puzzle,
+    part: u8,
+    answer: String,
+    wait: bool,
+    submit: impl FnOnce(Puzzle, u8, &str) -> Option<SubmissionOutcome>,
+) -> bool {
+    if let Err(reason) = answers.check_submission(puzzle.day, part, &answer) {
+        eprintln!("Refusing to submit: {reason}");
+        return false;
+    }
+
+    if let Some(remaining) = cooldowns.remaining(puzzle.day, part, cooldowns::now()) {
+        if wait {
+            println!("Waiting {}s before submitting...", remaining.as_secs());
+            thread::sleep(remaining);
+        } else {
+            eprintln!(
+                "Refusing to submit: {}s left to wait before submitting again. Pass --wait to submit once it has elapsed.",
+                remaining.as_secs()
+            );
+            return false;
+        }
+    }
+
+    let Some(outcome) = submit(puzzle, part, &answer) else {
+        return false;
+    };
+
+    match outcome {
+        SubmissionOutcome::Correct => {
+            answers.accept(puzzle.day, part, &answer);
+            cooldowns.clear(puzzle.day, part);
+        }
+        SubmissionOutcome::Wrong { hint, wait } => {
+            answers.reject(puzzle.day, WrongGuess { part, answer, hint });
+            if let Some(wait) = wait {
+                cooldowns.set(puzzle.day, part, cooldowns::now(), wait);
+            }
+        }
+        SubmissionOutcome::TooSoon { wait: Some(wait) } => {
+            cooldowns.set(puzzle.day, part, cooldowns::now(), wait);
+        }
+        _ => return false,
+    }
+
+    true
+}
+
+#[cfg(not(feature = "http-client"))]
+fn submit_answer(puzzle: Puzzle, part: u8, answer: &str) -> Option<SubmissionOutcome> {
+    use crate::template::aoc_cli;
+
+    if aoc_cli::check().is_err() {
+        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+        process::exit(1);
+    }
+
+    println!("Submitting result via aoc-cli...");
+
+    match aoc_cli::submit(puzzle, part, answer) {
+        Ok(outcome) => Some(outcome),
+        Err(e) => {
+            eprintln!("failed to call aoc-cli: {e}");
+            None
+        }
+    }
+}
+
+#[cfg(feature = "http-client")]
+fn submit_answer(puzzle: Puzzle, part: u8, answer: &str) -> Option<SubmissionOutcome> {
+    println!("Submitting result...");
+
+    match crate::template::aoc_client::submit(puzzle, part, answer) {
+        Ok(outcome) => Some(outcome),
+        Err(e) => {
+            eprintln!("failed to submit: {e}");
+            None
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Serializes the results of a day into the single-line record emitted by `--json`.
+#[must_use]
+pub fn results_to_json(results: &[PartResult]) -> String {
+    let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+    map.insert(
+        "parts".into(),
+        JsonValue::Array(results.iter().map(JsonValue::from).collect()),
+    );
+
+    // NOTE: `stringify` does not emit line breaks, which keeps the record on a single line.
+    JsonValue::Object(map).stringify().unwrap()
+}
+
+/// Parses a record emitted by `--json`.
+pub fn results_from_json(line: &str) -> Result<Vec<PartResult>, String> {
+    let json = JsonValue::from_str(line).or(Err("not a valid JSON record."))?;
+
+    let json_parts = json
+        .get::<HashMap<String, JsonValue>>()
+        .ok_or("expected JSON record to be an object.")?
+        .get("parts")
+        .ok_or("expected JSON record to have key `parts`.")?
+        .get::<Vec<JsonValue>>()
+        .ok_or("expected `json.parts` to be an array.")?;
+
+    json_parts.iter().map(PartResult::try_from).collect()
+}
+
+impl From<&PartResult> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &PartResult) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert(
+            "answer".into(),
+            match &value.answer {
+                Some(answer) => JsonValue::String(answer.clone()),
+                None => JsonValue::Null,
+            },
+        );
+        map.insert(
+            "verdict".into(),
+            JsonValue::String(value.verdict.as_str().into()),
+        );
+        map.insert(
+            "nanos".into(),
+            JsonValue::Number(value.duration.as_nanos() as f64),
+        );
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+        map.insert(
+            "stats".into(),
+            match &value.stats {
+                Some(stats) => JsonValue::from(stats),
+                None => JsonValue::Null,
+            },
+        );
+        map.insert(
+            "memory".into(),
+            match &value.memory {
+                Some(memory) => JsonValue::from(memory),
+                None => JsonValue::Null,
+            },
+        );
+        map.insert(
+            "failure".into(),
+            match &value.failure {
+                Some(failure) => JsonValue::String(failure.clone()),
+                None => JsonValue::Null,
+            },
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartResult {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected part to be a JSON object.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .ok_or("Expected part.part to be a number.")?;
+
+        let answer = json
+            .get("answer")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected part.answer to be null or string.")?;
+
+        let verdict = json
+            .get("verdict")
+            .and_then(|v| v.get::<String>())
+            .and_then(|v| v.parse().ok())
+            .ok_or("Expected part.verdict to be a verdict.")?;
+
+        let nanos = json
+            .get("nanos")
+            .and_then(|v| v.get::<f64>())
+            .ok_or("Expected part.nanos to be a number.")?;
+
+        let samples = json
+            .get("samples")
+            .and_then(|v| v.get::<f64>())
+            .ok_or("Expected part.samples to be a number.")?;
+
+        let stats = match json.get("stats") {
+            Some(v) if !v.is_null() => Some(Stats::try_from(v)?),
+            _ => None,
+        };
+
+        let memory = match json.get("memory") {
+            Some(v) if !v.is_null() => Some(MemoryStats::try_from(v)?),
+            _ => None,
+        };
+
+        let failure = match json.get("failure") {
+            Some(v) if !v.is_null() => Some(
+                v.get::<String>()
+                    .ok_or("Expected part.failure to be null or string.")?
+                    .clone(),
+            ),
+            _ => None,
+        };
+
+        Ok(PartResult {
+            part: *part as u8,
+            answer: answer.cloned(),
+            verdict,
+            duration: Duration::from_nanos(*nanos as u64),
+            samples: *samples as u128,
+            stats,
+            memory,
+            failure,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{thread, time::Duration};
+
+    use super::{
+        results_from_json, results_to_json, run_guarded, timeout_from_secs, Failure, MemoryStats,
+        PartResult, Stats, Verdict, DEFAULT_TIMEOUT,
+    };
+
+    fn get_mock_results() -> Vec<PartResult> {
+        vec![
+            PartResult {
+                part: 1,
+                answer: Some("line one\nline \"two\"".into()),
+                verdict: Verdict::Correct,
+                duration: Duration::from_nanos(74130),
+                samples: 100,
+                stats: Stats::from_samples(&[Duration::from_nanos(74130); 100]),
+                memory: Some(MemoryStats {
+                    peak_bytes: 4096,
+                    total_bytes: 8192,
+                    allocations: 12,
+                }),
+                failure: None,
+            },
+            PartResult {
+                part: 2,
+                answer: None,
+                verdict: Verdict::Unknown,
+                duration: Duration::from_nanos(2_000_000_000),
+                samples: 1,
+                stats: None,
+                memory: None,
+                failure: Some("panicked: not yet implemented".into()),
+            },
+        ]
+    }
+
+    #[test]
+    fn serializes_to_a_single_line() {
+        let record = results_to_json(&get_mock_results());
+        assert!(!record.contains('\n'));
+    }
+
+    #[test]
+    fn round_trips_results() {
+        let results = results_from_json(&results_to_json(&get_mock_results())).unwrap();
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].part, 1);
+        assert_eq!(results[0].answer.as_deref(), Some("line one\nline \"two\""));
+        assert_eq!(results[0].verdict, Verdict::Correct);
+        assert_eq!(results[0].duration, Duration::from_nanos(74130));
+        assert_eq!(results[0].samples, 100);
+        assert_eq!(results[0].stats.map(|s| s.median), Some(74130.0));
+        assert_eq!(results[0].memory.map(|m| m.peak_bytes), Some(4096));
+        assert_eq!(results[1].part, 2);
+        assert_eq!(results[1].answer, None);
+        assert_eq!(results[1].verdict, Verdict::Unknown);
+        assert_eq!(results[1].duration, Duration::from_secs(2));
+        assert_eq!(results[1].stats, None);
+        assert_eq!(results[1].memory, None);
+        assert_eq!(
+            results[1].failure.as_deref(),
+            Some("panicked: not yet implemented")
+        );
+    }
+
+    #[test]
+    fn catches_panics() {
+        let result = run_guarded(|| -> u32 { panic!("no solution for {}", 42) }, None);
+        assert_eq!(result, Err(Failure::Panicked("no solution for 42".into())));
+        assert_eq!(run_guarded(|| 42, None), Ok(42));
+    }
+
+    #[test]
+    fn abandons_parts_that_time_out() {
+        let timeout = Duration::from_millis(10);
+        let result = run_guarded(|| thread::sleep(Duration::from_secs(5)), Some(timeout));
+        assert_eq!(result, Err(Failure::TimedOut(timeout)));
+        assert_eq!(
+            Failure::TimedOut(Duration::from_secs(10)).to_string(),
+            "timed out after 10s"
+        );
+    }
+
+    #[test]
+    fn parses_timeouts() {
+        assert_eq!(timeout_from_secs(None), Some(DEFAULT_TIMEOUT));
+        assert_eq!(timeout_from_secs(Some(0)), None);
+        assert_eq!(timeout_from_secs(Some(3)), Some(Duration::from_secs(3)));
+    }
+
+    #[test]
+    fn rejects_other_output() {
+        assert!(results_from_json("Part 1: 0 (74.13ns @ 100000 samples)").is_err());
+        assert!(results_from_json(r#"{ "data": [] }"#).is_err());
+        assert!(results_from_json(r#"{ "parts": [{ "part": 1 }] }"#).is_err());
+    }
+}
@@ -0,0 +1,337 @@
+/// Summary statistics of the samples taken while benching a solution part, and of its memory usage.
+use std::{collections::HashMap, fmt::Display, time::Duration};
+use tinyjson::JsonValue;
+
+/// z-score of a two-sided 95% confidence interval.
+const Z_95: f64 = 1.96;
+
+/// Samples further than this many interquartile ranges outside the quartiles are outliers (Tukey's fences).
+const OUTLIER_FENCE: f64 = 1.5;
+
+/// Statistics of a set of samples. All durations are in nanoseconds.
+///
+/// The mean, standard deviation and confidence interval are computed without outliers,
+/// the median and percentiles over all samples.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stats {
+    pub samples: u128,
+    pub outliers: u128,
+    pub median: f64,
+    pub mean: f64,
+    pub p5: f64,
+    pub p95: f64,
+    pub std_dev: f64,
+    /// Bounds of the 95% confidence interval of the mean.
+    pub ci_low: f64,
+    pub ci_high: f64,
+}
+
+impl Stats {
+    /// Computes the statistics of a set of samples, returns `None` if there are none.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted: Vec<f64> = samples.iter().map(|d| d.as_nanos() as f64).collect();
+        sorted.sort_unstable_by(f64::total_cmp);
+
+        let q1 = percentile(&sorted, 0.25);
+        let q3 = percentile(&sorted, 0.75);
+        let fence = OUTLIER_FENCE * (q3 - q1);
+        let kept: Vec<f64> = sorted
+            .iter()
+            .copied()
+            .filter(|x| (q1 - fence..=q3 + fence).contains(x))
+            .collect();
+
+        let n = kept.len() as f64;
+        let mean = kept.iter().sum::<f64>() / n;
+        let std_dev = if kept.len() > 1 {
+            (kept.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0)).sqrt()
+        } else {
+            0.0
+        };
+        let margin = Z_95 * std_dev / n.sqrt();
+
+        Some(Stats {
+            samples: sorted.len() as u128,
+            outliers: (sorted.len() - kept.len()) as u128,
+            median: percentile(&sorted, 0.5),
+            mean,
+            p5: percentile(&sorted, 0.05),
+            p95: percentile(&sorted, 0.95),
+            std_dev,
+            ci_low: mean - margin,
+            ci_high: mean + margin,
+        })
+    }
+
+    /// The median as a duration, which is the figure reported for a benched part.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn median_duration(&self) -> Duration {
+        Duration::from_nanos(self.median as u64)
+    }
+}
+
+impl Display for Stats {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let nanos = |x: f64| Duration::from_nanos(x.max(0.0) as u64);
+
+        write!(
+            f,
+            "{:.1?} @ {} samples, p5 {:.1?}, p95 {:.1?}, σ {:.1?}",
+            nanos(self.median),
+            self.samples,
+            nanos(self.p5),
+            nanos(self.p95),
+            nanos(self.std_dev),
+        )?;
+
+        if self.outliers > 0 {
+            write!(f, ", {} outliers", self.outliers)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Memory allocated while running a part once, see the `count-allocs` feature.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MemoryStats {
+    /// The most memory held at once, on top of what was allocated before the part started.
+    pub peak_bytes: u64,
+    /// The sum of all allocations, including memory that was freed again.
+    pub total_bytes: u64,
+    pub allocations: u64,
+}
+
+impl Display for MemoryStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} peak, {} allocations, {} total",
+            format_bytes(self.peak_bytes),
+            self.allocations,
+            format_bytes(self.total_bytes)
+        )
+    }
+}
+
+/// Formats a number of bytes with a binary prefix, e.g. `1.5 MiB`.
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut value = bytes as f64;
+    let mut unit = "B";
+    for next in UNITS {
+        if value < 1024.0 {
+            break;
+        }
+        value /= 1024.0;
+        unit = next;
+    }
+    format!("{value:.1} {unit}")
+}
+
+/// Linearly interpolated percentile of sorted samples.
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    let (lower, upper, weight) = {
+        let rank = p * (sorted.len() - 1) as f64;
+        (rank.floor() as usize, rank.ceil() as usize, rank.fract())
+    };
+
+    sorted[lower] + (sorted[upper] - sorted[lower]) * weight
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Stats> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &Stats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+        map.insert("outliers".into(), JsonValue::Number(value.outliers as f64));
+        map.insert("median".into(), JsonValue::Number(value.median));
+        map.insert("mean".into(), JsonValue::Number(value.mean));
+        map.insert("p5".into(), JsonValue::Number(value.p5));
+        map.insert("p95".into(), JsonValue::Number(value.p95));
+        map.insert("std_dev".into(), JsonValue::Number(value.std_dev));
+        map.insert("ci_low".into(), JsonValue::Number(value.ci_low));
+        map.insert("ci_high".into(), JsonValue::Number(value.ci_high));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Stats {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected stats to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected stats.{key} to be a number."))
+        };
+
+        Ok(Stats {
+            samples: number("samples")? as u128,
+            outliers: number("outliers")? as u128,
+            median: number("median")?,
+            mean: number("mean")?,
+            p5: number("p5")?,
+            p95: number("p95")?,
+            std_dev: number("std_dev")?,
+            ci_low: number("ci_low")?,
+            ci_high: number("ci_high")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&MemoryStats> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &MemoryStats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "peak_bytes".into(),
+            JsonValue::Number(value.peak_bytes as f64),
+        );
+        map.insert(
+            "total_bytes".into(),
+            JsonValue::Number(value.total_bytes as f64),
+        );
+        map.insert(
+            "allocations".into(),
+            JsonValue::Number(value.allocations as f64),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for MemoryStats {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected memory to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected memory.{key} to be a number."))
+        };
+
+        Ok(MemoryStats {
+            peak_bytes: number("peak_bytes")? as u64,
+            total_bytes: number("total_bytes")? as u64,
+            allocations: number("allocations")? as u64,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use tinyjson::JsonValue;
+
+    use super::{format_bytes, MemoryStats, Stats};
+
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().copied().map(Duration::from_nanos).collect()
+    }
+
+    #[test]
+    fn computes_percentiles() {
+        let samples: Vec<u64> = (1..=101).collect();
+        let stats = Stats::from_samples(&nanos(&samples)).unwrap();
+
+        assert_eq!(stats.samples, 101);
+        assert_eq!(stats.outliers, 0);
+        assert_eq!(stats.median, 51.0);
+        assert_eq!(stats.mean, 51.0);
+        assert_eq!(stats.p5, 6.0);
+        assert_eq!(stats.p95, 96.0);
+        assert!(stats.ci_low < 51.0 && stats.ci_high > 51.0);
+    }
+
+    #[test]
+    fn rejects_outliers() {
+        let stats = Stats::from_samples(&nanos(&[100, 101, 99, 100, 102, 98, 100, 5000])).unwrap();
+
+        assert_eq!(stats.samples, 8);
+        assert_eq!(stats.outliers, 1);
+        assert_eq!(stats.median, 100.0);
+        assert_eq!(stats.mean, 100.0);
+        assert!(stats.std_dev < 2.0);
+    }
+
+    #[test]
+    fn handles_single_samples() {
+        let stats = Stats::from_samples(&nanos(&[42])).unwrap();
+
+        assert_eq!(stats.median, 42.0);
+        assert_eq!(stats.std_dev, 0.0);
+        assert_eq!(stats.ci_low, 42.0);
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+
+    #[test]
+    fn round_trips_memory() {
+        let memory = MemoryStats {
+            peak_bytes: 2048,
+            total_bytes: 4096,
+            allocations: 3,
+        };
+        assert_eq!(MemoryStats::try_from(&JsonValue::from(&memory)), Ok(memory));
+        assert_eq!(
+            memory.to_string(),
+            "2.0 KiB peak, 3 allocations, 4.0 KiB total"
+        );
+    }
+
+    #[test]
+    fn round_trips_stats() {
+        let stats = Stats::from_samples(&nanos(&[100, 101, 99, 100, 5000])).unwrap();
+        let deserialized = Stats::try_from(&JsonValue::from(&stats)).unwrap();
+        assert_eq!(deserialized, stats);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
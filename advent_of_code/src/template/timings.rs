@@ -0,0 +1,665 @@
+use std::{
+    collections::HashMap, fmt::Display, fs, io::Error, path::PathBuf, str::FromStr, time::Duration,
+};
+use tinyjson::JsonValue;
+
+use crate::template::{
+    runner::PARSE_PART,
+    stats::{MemoryStats, Stats},
+    year_dir, Day, Year,
+};
+
+/// Timings are stored alongside the data files of each year.
+pub(crate) fn get_timings_path(year: Year) -> PathBuf {
+    year_dir(year).join("data").join("timings.json")
+}
+
+/// Represents benchmark times for a single day.
+/// The parts hold the formatted median duration, their statistics are kept alongside if they were benched.
+/// `parse` is only present for solutions that declare a separate parse step,
+/// the memory usage only if allocations were counted.
+#[derive(Clone, Debug)]
+pub struct Timing {
+    pub day: Day,
+    pub parse: Option<String>,
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+    pub parse_stats: Option<Stats>,
+    pub part_1_stats: Option<Stats>,
+    pub part_2_stats: Option<Stats>,
+    pub parse_memory: Option<MemoryStats>,
+    pub part_1_memory: Option<MemoryStats>,
+    pub part_2_memory: Option<MemoryStats>,
+    pub total_nanos: f64,
+}
+
+/// The change of the median duration of a part compared to a baseline.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimingChange {
+    pub day: Day,
+    pub part: u8,
+    pub baseline_nanos: f64,
+    pub nanos: f64,
+}
+
+/// Represents benchmark times for a set of days.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Timings {
+    pub data: Vec<Timing>,
+}
+
+impl Timings {
+    /// Dehydrate timings to a JSON file.
+    pub fn store_file(&self, year: Year) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(get_timings_path(year))?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate timings from a JSON file. If not present, returns empty timings.
+    pub fn read_from_file(year: Year) -> Self {
+        let s = fs::read_to_string(get_timings_path(year))
+            .map_err(|x| x.to_string())
+            .and_then(Timings::try_from);
+
+        match s {
+            Ok(timings) => timings,
+            Err(e) => {
+                eprintln!("{e}");
+                Timings::default()
+            }
+        }
+    }
+
+    /// Merge two sets of timings, overwriting `self` with `other` if present.
+    pub fn merge(&self, new: &Self) -> Self {
+        let mut data: Vec<Timing> = vec![];
+
+        for timing in &new.data {
+            data.push(timing.clone());
+        }
+
+        for timing in &self.data {
+            if !data.iter().any(|t| t.day == timing.day) {
+                data.push(timing.clone());
+            }
+        }
+
+        data.sort_unstable_by_key(|a| a.day);
+        Timings { data }
+    }
+
+    /// Sum up total duration of timings as millis.
+    pub fn total_millis(&self) -> f64 {
+        self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
+    }
+
+    pub fn is_day_complete(&self, day: Day) -> bool {
+        self.data
+            .iter()
+            .any(|t| t.day == day && t.part_1.is_some() && t.part_2.is_some())
+    }
+
+    /// Compares every part that is present in both `self` and `baseline`.
+    pub fn compare(&self, baseline: &Self) -> Vec<TimingChange> {
+        let mut changes = vec![];
+
+        for timing in &self.data {
+            let Some(baseline_timing) = baseline.data.iter().find(|t| t.day == timing.day) else {
+                continue;
+            };
+
+            for part in [PARSE_PART, 1, 2] {
+                if let (Some(nanos), Some(baseline_nanos)) =
+                    (timing.part_nanos(part), baseline_timing.part_nanos(part))
+                {
+                    changes.push(TimingChange {
+                        day: timing.day,
+                        part,
+                        baseline_nanos,
+                        nanos,
+                    });
+                }
+            }
+        }
+
+        changes
+    }
+}
+
+impl Timing {
+    /// A timing of a day without any parts.
+    #[must_use]
+    pub fn new(day: Day) -> Self {
+        Timing {
+            day,
+            parse: None,
+            part_1: None,
+            part_2: None,
+            parse_stats: None,
+            part_1_stats: None,
+            part_2_stats: None,
+            parse_memory: None,
+            part_1_memory: None,
+            part_2_memory: None,
+            total_nanos: 0_f64,
+        }
+    }
+
+    /// The median duration of a part, or of the parse step for [`PARSE_PART`].
+    /// Falls back to the formatted duration for timings stored without statistics.
+    pub fn part_nanos(&self, part: u8) -> Option<f64> {
+        let (formatted, stats) = match part {
+            PARSE_PART => (&self.parse, &self.parse_stats),
+            1 => (&self.part_1, &self.part_1_stats),
+            2 => (&self.part_2, &self.part_2_stats),
+            _ => return None,
+        };
+
+        stats
+            .map(|s| s.median)
+            .or_else(|| formatted.as_deref().and_then(parse_duration))
+    }
+
+    /// The memory usage of the whole day: the highest peak of its steps, and the sum of their allocations.
+    pub fn memory(&self) -> Option<MemoryStats> {
+        [self.parse_memory, self.part_1_memory, self.part_2_memory]
+            .into_iter()
+            .flatten()
+            .reduce(|a, b| MemoryStats {
+                peak_bytes: a.peak_bytes.max(b.peak_bytes),
+                total_bytes: a.total_bytes + b.total_bytes,
+                allocations: a.allocations + b.allocations,
+            })
+    }
+}
+
+impl TimingChange {
+    /// Names the part, e.g. `part 1` or `parse`.
+    pub fn label(&self) -> String {
+        if self.part == PARSE_PART {
+            "parse".into()
+        } else {
+            format!("part {}", self.part)
+        }
+    }
+
+    /// The relative change in percent, positive if the part got slower.
+    pub fn percent(&self) -> f64 {
+        (self.nanos / self.baseline_nanos - 1.0) * 100.0
+    }
+
+    /// Whether the part got slower by more than `threshold` percent.
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.percent() > threshold
+    }
+
+    /// Whether the part got faster by more than `threshold` percent.
+    pub fn is_improvement(&self, threshold: f64) -> bool {
+        self.percent() < -threshold
+    }
+}
+
+impl Display for TimingChange {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Day {}, {}: {:.1?} → {:.1?} ({:+.1}%)",
+            self.day,
+            self.label(),
+            Duration::from_nanos(self.baseline_nanos as u64),
+            Duration::from_nanos(self.nanos as u64),
+            self.percent()
+        )
+    }
+}
+
+/// Parses a duration formatted with `{:.1?}`, e.g. `241.1µs`, into nanoseconds.
+fn parse_duration(s: &str) -> Option<f64> {
+    let units = [("ns", 1.0), ("µs", 1e3), ("ms", 1e6), ("s", 1e9)];
+    let (value, factor) = units
+        .iter()
+        .find_map(|(unit, factor)| Some((s.strip_suffix(unit)?, factor)))?;
+    value.parse::<f64>().ok().map(|v| v * factor)
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Timings> for JsonValue {
+    fn from(value: Timings) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Timings {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Timings {
+            data: json_data
+                .iter()
+                .map(Timing::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Timing> for JsonValue {
+    fn from(value: &Timing) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
+
+        let parse = value.parse.clone().map(JsonValue::String);
+        let part_1 = value.part_1.clone().map(JsonValue::String);
+        let part_2 = value.part_2.clone().map(JsonValue::String);
+
+        map.insert(
+            "parse".into(),
+            match parse {
+                Some(x) => x,
+                None => JsonValue::Null,
+            },
+        );
+
+        map.insert(
+            "part_1".into(),
+            match part_1 {
+                Some(x) => x,
+                None => JsonValue::Null,
+            },
+        );
+
+        map.insert(
+            "part_2".into(),
+            match part_2 {
+                Some(x) => x,
+                None => JsonValue::Null,
+            },
+        );
+
+        for (key, stats) in [
+            ("parse_stats", &value.parse_stats),
+            ("part_1_stats", &value.part_1_stats),
+            ("part_2_stats", &value.part_2_stats),
+        ] {
+            map.insert(
+                key.into(),
+                match stats {
+                    Some(stats) => JsonValue::from(stats),
+                    None => JsonValue::Null,
+                },
+            );
+        }
+
+        for (key, memory) in [
+            ("parse_memory", &value.parse_memory),
+            ("part_1_memory", &value.part_1_memory),
+            ("part_2_memory", &value.part_2_memory),
+        ] {
+            map.insert(
+                key.into(),
+                match memory {
+                    Some(memory) => JsonValue::from(memory),
+                    None => JsonValue::Null,
+                },
+            );
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Timing {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected timing to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected timing.day to be a Day struct.")?;
+
+        let part_1 = json
+            .get("part_1")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected timing.part_1 to be null or string.")?;
+
+        let part_2 = json
+            .get("part_2")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected timing.part_2 to be null or string.")?;
+
+        let total_nanos = json
+            .get("total_nanos")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected timing.total_nanos to be a number.")?;
+
+        // NOTE: the parse step, statistics and memory usage are optional, timings stored by earlier versions don't have them.
+        let parse = json
+            .get("parse")
+            .map_or(Some(None), |v| {
+                if v.is_null() {
+                    Some(None)
+                } else {
+                    v.get::<String>().map(Some)
+                }
+            })
+            .ok_or("Expected timing.parse to be null or string.")?;
+
+        let stats = |key: &str| match json.get(key) {
+            Some(v) if !v.is_null() => Stats::try_from(v).map(Some),
+            _ => Ok(None),
+        };
+
+        let memory = |key: &str| match json.get(key) {
+            Some(v) if !v.is_null() => MemoryStats::try_from(v).map(Some),
+            _ => Ok(None),
+        };
+
+        Ok(Timing {
+            day,
+            parse: parse.cloned(),
+            part_1: part_1.cloned(),
+            part_2: part_2.cloned(),
+            parse_stats: stats("parse_stats")?,
+            part_1_stats: stats("part_1_stats")?,
+            part_2_stats: stats("part_2_stats")?,
+            parse_memory: memory("parse_memory")?,
+            part_1_memory: memory("part_1_memory")?,
+            part_2_memory: memory("part_2_memory")?,
+            total_nanos,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use crate::day;
+
+    use super::{Timing, Timings};
+
+    fn get_mock_timings() -> Timings {
+        Timings {
+            data: vec![
+                Timing {
+                    part_1: Some("10ms".into()),
+                    part_2: Some("20ms".into()),
+                    total_nanos: 3e+10,
+                    ..Timing::new(day!(1))
+                },
+                Timing {
+                    part_1: Some("30ms".into()),
+                    part_2: Some("40ms".into()),
+                    total_nanos: 7e+10,
+                    ..Timing::new(day!(2))
+                },
+                Timing {
+                    part_1: Some("40ms".into()),
+                    total_nanos: 4e+10,
+                    ..Timing::new(day!(4))
+                },
+            ],
+        }
+    }
+
+    mod deserialization {
+        use crate::{day, template::timings::Timings};
+
+        #[test]
+        fn handles_json_timings() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000000 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data.len(), 1);
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.day, day!(1));
+            assert_eq!(timing.part_1, Some("1ms".to_string()));
+            assert_eq!(timing.part_2, None);
+            assert_eq!(timing.parse, None);
+            assert_eq!(timing.total_nanos, 1_000_000_000_f64);
+        }
+
+        #[test]
+        fn handles_empty_timings() {
+            let json = r#"{ "data": [] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data.len(), 0);
+        }
+
+        #[test]
+        #[should_panic]
+        fn panics_for_invalid_json() {
+            let json = r#"{}"#.to_string();
+            Timings::try_from(json).unwrap();
+        }
+
+        #[test]
+        #[should_panic]
+        fn panics_for_malformed_timings() {
+            let json = r#"{ "data": [{ "day": "01" }, { "day": "26" }, { "day": "02", "part_2": null, "total_nanos": 0 }] }"#.to_string();
+            Timings::try_from(json).unwrap();
+        }
+    }
+
+    mod serialization {
+        use super::get_mock_timings;
+        use crate::template::{stats::Stats, timings::Timings};
+        use std::{collections::HashMap, time::Duration};
+        use tinyjson::JsonValue;
+
+        #[test]
+        fn serializes_timings() {
+            let timings = get_mock_timings();
+            let value = JsonValue::try_from(timings).unwrap();
+            assert_eq!(
+                value
+                    .get::<HashMap<String, JsonValue>>()
+                    .unwrap()
+                    .get("data")
+                    .unwrap()
+                    .get::<Vec<JsonValue>>()
+                    .unwrap()
+                    .len(),
+                3
+            );
+        }
+
+        #[test]
+        fn round_trips_stats() {
+            let mut timings = get_mock_timings();
+            timings.data[0].parse = Some("1ms".into());
+            timings.data[0].part_1_stats =
+                Stats::from_samples(&[Duration::from_millis(10), Duration::from_millis(12)]);
+
+            let json = JsonValue::from(timings).stringify().unwrap();
+            let deserialized = Timings::try_from(json).unwrap();
+
+            let stats = deserialized.data[0].part_1_stats.unwrap();
+            assert_eq!(stats.samples, 2);
+            assert_eq!(stats.median, 11_000_000_f64);
+            assert_eq!(deserialized.data[0].part_2_stats, None);
+            assert_eq!(deserialized.data[0].parse.as_deref(), Some("1ms"));
+            assert_eq!(deserialized.data[0].part_nanos(0), Some(1_000_000.0));
+        }
+    }
+
+    mod is_day_complete {
+        use crate::{
+            day,
+            template::timings::{Timing, Timings},
+        };
+
+        #[test]
+        fn handles_completed_days() {
+            let timings = Timings {
+                data: vec![Timing {
+                    part_1: Some("1ms".into()),
+                    part_2: Some("2ms".into()),
+                    total_nanos: 3_000_000_000_f64,
+                    ..Timing::new(day!(1))
+                }],
+            };
+
+            assert_eq!(timings.is_day_complete(day!(1)), true);
+        }
+
+        #[test]
+        fn handles_partial_days() {
+            let timings = Timings {
+                data: vec![Timing {
+                    part_1: Some("1ms".into()),
+                    total_nanos: 1_000_000_000_f64,
+                    ..Timing::new(day!(1))
+                }],
+            };
+
+            assert_eq!(timings.is_day_complete(day!(1)), false);
+        }
+
+        #[test]
+        fn handles_uncompleted_days() {
+            let timings = Timings {
+                data: vec![Timing::new(day!(1))],
+            };
+
+            assert_eq!(timings.is_day_complete(day!(1)), false);
+        }
+    }
+
+    mod compare {
+        use crate::{
+            day,
+            template::stats::Stats,
+            template::timings::{parse_duration, Timings},
+        };
+        use std::time::Duration;
+
+        use super::get_mock_timings;
+
+        #[test]
+        fn compares_overlapping_parts() {
+            let baseline = get_mock_timings();
+            let mut timings = get_mock_timings();
+            timings.data.remove(0);
+            timings.data[0].part_1 = Some("45ms".into());
+            timings.data[1].part_2_stats = Stats::from_samples(&[Duration::from_millis(5)]);
+
+            let changes = timings.compare(&baseline);
+
+            // day 4 has no stored part 2 to compare to.
+            assert_eq!(changes.len(), 3);
+            assert_eq!(changes[0].day, day!(2));
+            assert_eq!(changes[0].part, 1);
+            assert_eq!(changes[0].percent(), 50.0);
+            assert!(changes[0].is_regression(10.0));
+            assert!(!changes[0].is_regression(50.0));
+            assert_eq!(changes[1].percent(), 0.0);
+            assert!(!changes[1].is_improvement(10.0));
+            assert_eq!(changes[2].day, day!(4));
+            assert_eq!(changes[2].part, 1);
+        }
+
+        #[test]
+        fn prefers_stats_over_formatted_durations() {
+            let mut timings = get_mock_timings();
+            timings.data[0].part_1_stats = Stats::from_samples(&[Duration::from_millis(5)]);
+
+            assert_eq!(timings.data[0].part_nanos(1), Some(5_000_000.0));
+            assert_eq!(timings.data[0].part_nanos(2), Some(20_000_000.0));
+            assert_eq!(timings.data[2].part_nanos(2), None);
+            assert_eq!(Timings::default().compare(&timings), vec![]);
+        }
+
+        #[test]
+        fn parses_formatted_durations() {
+            assert_eq!(parse_duration("500.0ns"), Some(500.0));
+            assert_eq!(parse_duration("241.1µs"), Some(241_100.0));
+            assert_eq!(parse_duration("2.0ms"), Some(2_000_000.0));
+            assert_eq!(parse_duration("1.5s"), Some(1_500_000_000.0));
+            assert_eq!(parse_duration("-"), None);
+        }
+    }
+
+    mod merge {
+        use crate::{
+            day,
+            template::timings::{Timing, Timings},
+        };
+
+        use super::get_mock_timings;
+
+        #[test]
+        fn handles_disjunct_timings() {
+            let timings = get_mock_timings();
+            let other = Timings {
+                data: vec![Timing::new(day!(3))],
+            };
+            let merged = timings.merge(&other);
+            assert_eq!(merged.data.len(), 4);
+            assert_eq!(merged.data[0].day, day!(1));
+            assert_eq!(merged.data[1].day, day!(2));
+            assert_eq!(merged.data[2].day, day!(3));
+            assert_eq!(merged.data[3].day, day!(4));
+        }
+
+        #[test]
+        fn handles_overlapping_timings() {
+            let timings = get_mock_timings();
+
+            let other = Timings {
+                data: vec![Timing::new(day!(2))],
+            };
+            let merged = timings.merge(&other);
+
+            assert_eq!(merged.data.len(), 3);
+            assert_eq!(merged.data[0].day, day!(1));
+            assert_eq!(merged.data[1].day, day!(2));
+            assert_eq!(merged.data[1].total_nanos, 0_f64);
+            assert_eq!(merged.data[2].day, day!(4));
+        }
+
+        #[test]
+        fn handles_empty_timings() {
+            let timings = Timings::default();
+            let other = get_mock_timings();
+            let merged = timings.merge(&other);
+            assert_eq!(merged.data.len(), 3);
+        }
+
+        #[test]
+        fn handles_empty_other_timings() {
+            let timings = get_mock_timings();
+            let other = Timings::default();
+            let merged = timings.merge(&other);
+            assert_eq!(merged.data.len(), 3);
+        }
+    }
+}
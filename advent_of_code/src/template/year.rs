@@ -0,0 +1,138 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[cfg(feature = "today")]
+use chrono::{Datelike, FixedOffset, Utc};
+
+#[cfg(feature = "today")]
+const SERVER_UTC_OFFSET: i32 = -5;
+
+/// The year of the first advent of code.
+const FIRST_YEAR: u16 = 2015;
+
+/// A valid year of advent of code (i.e. an integer from 2015 onward).
+///
+/// # Display
+/// This value displays as a four digit number.
+///
+/// ```
+/// # use advent_of_code::template::Year;
+/// let year = Year::new(2022).unwrap();
+/// assert_eq!(year.to_string(), "2022")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// Creates a [`Year`] from the provided value if it's in the valid range,
+    /// returns [`None`] otherwise.
+    pub fn new(year: u16) -> Option<Self> {
+        if year < FIRST_YEAR {
+            return None;
+        }
+        Some(Self(year))
+    }
+
+    // Not part of the public API
+    #[doc(hidden)]
+    pub const fn __new_unchecked(year: u16) -> Self {
+        Self(year)
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    pub fn into_inner(self) -> u16 {
+        self.0
+    }
+}
+
+#[cfg(feature = "today")]
+impl Year {
+    /// Returns the current year as observed by the advent of code server.
+    pub fn today() -> Option<Self> {
+        let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
+        let today = Utc::now().with_timezone(&offset);
+        Self::new(u16::try_from(today.year()).ok()?)
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}", self.0)
+    }
+}
+
+impl PartialEq<u16> for Year {
+    fn eq(&self, other: &u16) -> bool {
+        self.0.eq(other)
+    }
+}
+
+impl PartialOrd<u16> for Year {
+    fn partial_cmp(&self, other: &u16) -> Option<std::cmp::Ordering> {
+        self.0.partial_cmp(other)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "expecting a year from {FIRST_YEAR} onward")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`Year`] value in a const context.
+#[macro_export]
+macro_rules! year {
+    ($year:expr) => {{
+        const _ASSERT: () = assert!(
+            $year >= 2015,
+            concat!(
+                "invalid year `",
+                $year,
+                "`, expecting a value from 2015 onward"
+            ),
+        );
+        $crate::template::Year::__new_unchecked($year)
+    }};
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Year;
+
+    #[test]
+    fn parses_valid_years() {
+        assert_eq!("2015".parse::<Year>().ok(), Some(Year(2015)));
+        assert_eq!("2023".parse::<Year>().ok(), Some(Year(2023)));
+    }
+
+    #[test]
+    fn rejects_invalid_years() {
+        assert!("2014".parse::<Year>().is_err());
+        assert!("23".parse::<Year>().is_err());
+        assert!("twenty".parse::<Year>().is_err());
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,332 @@
+use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::report::Format;
+use advent_of_code::template::run_multi::Runner;
+use advent_of_code::template::runner::Solution;
+use advent_of_code::template::{year_dir, Year};
+use args::{parse, AppArguments};
+
+mod args {
+    use advent_of_code::template::commands::time;
+    use advent_of_code::template::report::Format;
+    use advent_of_code::template::runner::timeout_from_secs;
+    use advent_of_code::template::{Day, Puzzle, Year};
+    use std::{env, path::PathBuf, process, time::Duration};
+
+    pub enum AppArguments {
+        Download {
+            puzzle: Puzzle,
+        },
+        Read {
+            puzzle: Puzzle,
+        },
+        Scaffold {
+            puzzle: Puzzle,
+            download: bool,
+        },
+        Solve {
+            puzzle: Puzzle,
+            release: bool,
+            dhat: bool,
+            submit: Option<u8>,
+            wait: bool,
+            timeout: Option<Duration>,
+            format: Format,
+        },
+        All {
+            year: Year,
+            release: bool,
+            isolated: bool,
+            jobs: usize,
+            timeout: Option<Duration>,
+            format: Format,
+        },
+        Time {
+            year: Year,
+            isolated: bool,
+            all: bool,
+            day: Option<Day>,
+            store: bool,
+            accept: bool,
+            threshold: f64,
+            timeout: Option<Duration>,
+            format: Format,
+            report: Option<PathBuf>,
+        },
+        History {
+            year: Year,
+            day: Day,
+            ascii: bool,
+        },
+        #[cfg(feature = "today")]
+        Today {
+            puzzle: Puzzle,
+        },
+    }
+
+    impl AppArguments {
+        pub fn year(&self) -> Year {
+            match self {
+                AppArguments::Download { puzzle }
+                | AppArguments::Read { puzzle }
+                | AppArguments::Scaffold { puzzle, .. }
+                | AppArguments::Solve { puzzle, .. } => puzzle.year,
+                #[cfg(feature = "today")]
+                AppArguments::Today { puzzle } => puzzle.year,
+                AppArguments::All { year, .. }
+                | AppArguments::Time { year, .. }
+                | AppArguments::History { year, .. } => *year,
+            }
+        }
+    }
+
+    /// Reads the year from the `--year` flag, falling back to the `AOC_YEAR` environment variable.
+    fn parse_year(args: &mut pico_args::Arguments) -> Result<Year, Box<dyn std::error::Error>> {
+        if let Some(year) = args.opt_value_from_str("--year")? {
+            return Ok(year);
+        }
+
+        match env::var("AOC_YEAR") {
+            Ok(year) => Ok(year.parse()?),
+            Err(_) => Err("no year specified. Pass `--year <year>` or set `AOC_YEAR`.".into()),
+        }
+    }
+
+    /// Reads the year (see [`parse_year`]) followed by the day of a puzzle.
+    fn parse_puzzle(args: &mut pico_args::Arguments) -> Result<Puzzle, Box<dyn std::error::Error>> {
+        let year = parse_year(args)?;
+        Ok(Puzzle::new(year, args.free_from_str()?))
+    }
+
+    /// Reads the `--timeout` flag in seconds, see [`timeout_from_secs`].
+    fn parse_timeout(
+        args: &mut pico_args::Arguments,
+    ) -> Result<Option<Duration>, Box<dyn std::error::Error>> {
+        Ok(timeout_from_secs(args.opt_value_from_str("--timeout")?))
+    }
+
+    /// Reads the `--format` flag, defaults to text.
+    fn parse_format(args: &mut pico_args::Arguments) -> Result<Format, Box<dyn std::error::Error>> {
+        Ok(args.opt_value_from_str("--format")?.unwrap_or_default())
+    }
+
+    pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
+        let mut args = pico_args::Arguments::from_env();
+
+        let app_args = match args.subcommand()?.as_deref() {
+            Some("all") => AppArguments::All {
+                year: parse_year(&mut args)?,
+                release: args.contains("--release"),
+                isolated: args.contains("--isolated"),
+                jobs: args.opt_value_from_str("--jobs")?.unwrap_or(1),
+                timeout: parse_timeout(&mut args)?,
+                format: parse_format(&mut args)?,
+            },
+            Some("time") => {
+                let year = parse_year(&mut args)?;
+
+                if let Some(day) = args.opt_value_from_str("--history")? {
+                    let ascii = args.contains("--ascii");
+                    return finish(args, AppArguments::History { year, day, ascii });
+                }
+
+                let isolated = args.contains("--isolated");
+                let all = args.contains("--all");
+                let store = args.contains("--store");
+                let accept = args.contains("--accept");
+                let threshold = args
+                    .opt_value_from_str("--threshold")?
+                    .unwrap_or(time::DEFAULT_THRESHOLD);
+                let timeout = parse_timeout(&mut args)?;
+                let format = parse_format(&mut args)?;
+                let report = args.opt_value_from_str("--report")?;
+
+                AppArguments::Time {
+                    year,
+                    isolated,
+                    all,
+                    day: args.opt_free_from_str()?,
+                    store,
+                    accept,
+                    threshold,
+                    timeout,
+                    format,
+                    report,
+                }
+            }
+            Some("download") => AppArguments::Download {
+                puzzle: parse_puzzle(&mut args)?,
+            },
+            Some("read") => AppArguments::Read {
+                puzzle: parse_puzzle(&mut args)?,
+            },
+            Some("scaffold") => AppArguments::Scaffold {
+                puzzle: parse_puzzle(&mut args)?,
+                download: args.contains("--download"),
+            },
+            Some("solve") => AppArguments::Solve {
+                puzzle: parse_puzzle(&mut args)?,
+                release: args.contains("--release"),
+                submit: args.opt_value_from_str("--submit")?,
+                wait: args.contains("--wait"),
+                dhat: args.contains("--dhat"),
+                timeout: parse_timeout(&mut args)?,
+                format: parse_format(&mut args)?,
+            },
+            #[cfg(feature = "today")]
+            Some("today") => AppArguments::Today {
+                puzzle: Puzzle::today().ok_or(
+                    "`today` command can only be run between the 1st and \
+                        the 25th of december. Please use `scaffold` with a specific day.",
+                )?,
+            },
+            Some(x) => {
+                eprintln!("Unknown command: {x}");
+                process::exit(1);
+            }
+            None => {
+                eprintln!("No command specified.");
+                process::exit(1);
+            }
+        };
+
+        finish(args, app_args)
+    }
+
+    fn finish(
+        args: pico_args::Arguments,
+        app_args: AppArguments,
+    ) -> Result<AppArguments, Box<dyn std::error::Error>> {
+        let remaining = args.finish();
+        if !remaining.is_empty() {
+            eprintln!("Warning: unknown argument(s): {remaining:?}.");
+        }
+
+        Ok(app_args)
+    }
+}
+
+fn main() {
+    match parse() {
+        Err(err) => {
+            eprintln!("Error: {err}");
+            std::process::exit(1);
+        }
+        Ok(args) => {
+            check_year(args.year());
+            run(args);
+        }
+    };
+}
+
+/// Every year lives in its own directory of the workspace, with its solutions, data files and README.
+fn check_year(year: Year) {
+    let path = year_dir(year);
+
+    if !path.is_dir() {
+        eprintln!(
+            "Error: could not find solutions for {year} in \"{}\".",
+            path.display()
+        );
+        std::process::exit(1);
+    }
+}
+
+/// The registered solutions of each year, see `days!`.
+fn solutions(year: Year) -> &'static [Solution] {
+    match year.into_inner() {
+        2021 => advent_of_code_2021::days::SOLUTIONS,
+        2022 => advent_of_code_2022::days::SOLUTIONS,
+        2023 => advent_of_code_2023::days::SOLUTIONS,
+        _ => {
+            eprintln!(
+                "Error: the solutions of {year} are not wired into the runner. Add \"{year}\" to the \
+                workspace members and `advent_of_code_{year} = {{ path = \"{year}\" }}` to the dependencies \
+                in the root Cargo.toml, and a match arm for {year} to `solutions` in src/main.rs."
+            );
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Solutions are called in-process unless `--isolated` asks for a process per day.
+/// Solutions may print to stdout themselves, so they run isolated when stdout is reserved for a report,
+/// and when several days run at the same time, as only the output of a child process can be buffered.
+fn runner(
+    year: Year,
+    isolated: bool,
+    is_release: bool,
+    jobs: usize,
+    format: Format,
+) -> Runner<'static> {
+    if isolated || jobs > 1 || format != Format::Text {
+        Runner::Isolated { is_release }
+    } else {
+        Runner::InProcess(solutions(year))
+    }
+}
+
+fn run(args: AppArguments) {
+    match args {
+        AppArguments::All {
+            year,
+            release,
+            isolated,
+            jobs,
+            timeout,
+            format,
+        } => all::handle(
+            year,
+            runner(year, isolated, release, jobs, format),
+            jobs,
+            timeout,
+            format,
+        ),
+        AppArguments::Time {
+            year,
+            isolated,
+            day,
+            all,
+            store,
+            accept,
+            threshold,
+            timeout,
+            format,
+            report,
+        } => time::handle(
+            year,
+            runner(year, isolated, true, 1, format),
+            day,
+            all,
+            store,
+            accept,
+            threshold,
+            timeout,
+            format,
+            report,
+        ),
+        AppArguments::History { year, day, ascii } => time::history(year, day, ascii),
+        AppArguments::Download { puzzle } => download::handle(puzzle),
+        AppArguments::Read { puzzle } => read::handle(puzzle),
+        AppArguments::Scaffold { puzzle, download } => {
+            scaffold::handle(puzzle);
+            if download {
+                download::handle(puzzle);
+            }
+        }
+        AppArguments::Solve {
+            puzzle,
+            release,
+            dhat,
+            submit,
+            wait,
+            timeout,
+            format,
+        } => solve::handle(puzzle, release, dhat, submit, wait, timeout, format),
+        #[cfg(feature = "today")]
+        AppArguments::Today { puzzle } => {
+            scaffold::handle(puzzle);
+            download::handle(puzzle);
+            read::handle(puzzle);
+        }
+    }
+}